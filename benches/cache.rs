@@ -0,0 +1,71 @@
+use std::num::NonZeroUsize;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use fast_list::LruCache as FastLruCache;
+use lru::LruCache as CrateLruCache;
+
+/// A key pattern that hits the cache about a quarter of the time with a capacity of 1k.
+fn key(i: u64) -> u64 {
+    i.wrapping_mul(7919) % 4096
+}
+
+pub fn bench_lru_put(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lru_put_10k");
+    group.bench_function("fast_list_lru_put_10k", |b| {
+        b.iter(|| {
+            let mut cache = FastLruCache::new(1_000);
+            for i in 0..10_000 {
+                cache.put(key(i), i);
+            }
+            black_box(cache.len())
+        });
+    });
+    group.bench_function("lru_crate_put_10k", |b| {
+        b.iter(|| {
+            let mut cache = CrateLruCache::new(NonZeroUsize::new(1_000).unwrap());
+            for i in 0..10_000 {
+                cache.put(key(i), i);
+            }
+            black_box(cache.len())
+        });
+    });
+    group.finish();
+}
+
+pub fn bench_lru_get(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lru_get_or_put_10k");
+    group.bench_function("fast_list_lru_get_or_put_10k", |b| {
+        b.iter(|| {
+            let mut cache = FastLruCache::new(1_000);
+            let mut hits = 0;
+            for i in 0..10_000 {
+                if cache.get(&key(i)).is_some() {
+                    hits += 1;
+                } else {
+                    cache.put(key(i), i);
+                }
+            }
+            black_box(hits)
+        });
+    });
+    group.bench_function("lru_crate_get_or_put_10k", |b| {
+        b.iter(|| {
+            let mut cache = CrateLruCache::new(NonZeroUsize::new(1_000).unwrap());
+            let mut hits = 0;
+            for i in 0..10_000 {
+                if cache.get(&key(i)).is_some() {
+                    hits += 1;
+                } else {
+                    cache.put(key(i), i);
+                }
+            }
+            black_box(hits)
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_lru_put, bench_lru_get);
+
+criterion_main!(benches);
@@ -3,6 +3,8 @@ use criterion::{criterion_group, criterion_main, Criterion};
 use linked_list::{LinkedListWalker, Walker};
 
 use fast_list::LinkedList as FastLinkedList;
+use fast_list::{ForwardList, LinkedListIndex, UnrolledList};
+use slotmap::SlotMap;
 use std::collections::LinkedList as StdLinkedList;
 use std::collections::VecDeque;
 use std::vec::Vec;
@@ -257,7 +259,7 @@ pub fn bench_list_push_front(c: &mut Criterion) {
             let mut list = Vec::new();
         },
         {
-            list = vec![vec![i], list].concat();
+            list = [vec![i], list].concat();
         }
     );
     group.finish();
@@ -620,6 +622,8 @@ pub fn bench_list_pop_front(c: &mut Criterion) {
     group.finish();
 }
 
+// Iterating and counting is exactly what these benchmarks measure.
+#[allow(clippy::iter_count)]
 pub fn bench_list_iter(c: &mut Criterion) {
     let mut group = c.benchmark_group("list_iter_1k");
     bench!(
@@ -871,9 +875,6 @@ pub fn bench_list_iter_reverse(c: &mut Criterion) {
 }
 
 pub fn bench_list_split_off(c: &mut Criterion) {
-    // Just putting this here so I don't get out of scope errors because of the bench! macro.
-    let i = 0;
-
     let mut group = c.benchmark_group("list_split_off_10k");
 
     // fast list
@@ -1027,9 +1028,6 @@ pub fn bench_list_insert(c: &mut Criterion) {
 }
 
 pub fn bench_list_remove(c: &mut Criterion) {
-    // Just putting this here so I don't get out of scope errors because of the bench! macro.
-    let i = 0;
-
     let mut group: criterion::BenchmarkGroup<'_, criterion::measurement::WallTime> =
         c.benchmark_group("list_remove_1k");
 
@@ -1100,6 +1098,141 @@ pub fn bench_list_remove(c: &mut Criterion) {
     group.finish();
 }
 
+/// The node layout before items stopped storing their own index, kept to measure the difference.
+struct LegacyItem<T> {
+    #[allow(dead_code)]
+    index: LinkedListIndex,
+    value: T,
+    next_index: Option<LinkedListIndex>,
+    #[allow(dead_code)]
+    prev_index: Option<LinkedListIndex>,
+}
+
+pub fn bench_item_layout(c: &mut Criterion) {
+    let mut legacy = SlotMap::<LinkedListIndex, LegacyItem<u64>>::with_key();
+    let mut prev: Option<LinkedListIndex> = None;
+    let mut legacy_head = None;
+    for value in 0..100_000u64 {
+        let index = legacy.insert_with_key(|index| LegacyItem {
+            index,
+            value,
+            next_index: None,
+            prev_index: prev,
+        });
+        match prev {
+            Some(prev) => legacy[prev].next_index = Some(index),
+            None => legacy_head = Some(index),
+        }
+        prev = Some(index);
+    }
+    let mut list = FastLinkedList::new();
+    list.extend(0..100_000u64);
+
+    let mut group = c.benchmark_group("item_layout_iter_100k");
+    group.bench_function("legacy_layout_iter_100k", |b| {
+        b.iter(|| {
+            std::iter::successors(legacy_head, |index| legacy[*index].next_index)
+                .map(|index| legacy[index].value)
+                .sum::<u64>()
+        })
+    });
+    group.bench_function("current_layout_iter_100k", |b| {
+        b.iter(|| list.iter().map(|item| item.value).sum::<u64>())
+    });
+    group.finish();
+}
+
+pub fn bench_forward_list(c: &mut Criterion) {
+    let mut group = c.benchmark_group("queue_push_back_pop_front_10k");
+    bench!(
+        group,
+        "fast_list_queue_10k",
+        10_000,
+        {
+            let mut list = FastLinkedList::new();
+            list.extend(0..100u64);
+        },
+        {
+            list.push_back(0);
+            list.pop_front().expect("pop_front returned None");
+        }
+    );
+    bench!(
+        group,
+        "forward_list_queue_10k",
+        10_000,
+        {
+            let mut list = ForwardList::new();
+            list.extend(0..100u64);
+        },
+        {
+            list.push_back(0);
+            list.pop_front().expect("pop_front returned None");
+        }
+    );
+    group.finish();
+
+    let mut group = c.benchmark_group("forward_iter_10k");
+    let mut fast_list = FastLinkedList::new();
+    fast_list.extend(0..10_000u64);
+    let mut forward_list = ForwardList::new();
+    forward_list.extend(0..10_000u64);
+    group.bench_function("fast_list_iter_10k", |b| {
+        b.iter(|| fast_list.iter().map(|item| item.value).sum::<u64>())
+    });
+    group.bench_function("forward_list_iter_10k", |b| {
+        b.iter(|| forward_list.iter().map(|item| item.value).sum::<u64>())
+    });
+    group.finish();
+}
+
+pub fn bench_unrolled_list(c: &mut Criterion) {
+    let mut group = c.benchmark_group("unrolled_iter_100k");
+    let mut fast_list = FastLinkedList::new();
+    fast_list.extend(0..100_000u64);
+    let mut unrolled_list = UnrolledList::<u64>::new();
+    unrolled_list.extend(0..100_000u64);
+    let mut vec_deque = VecDeque::new();
+    vec_deque.extend(0..100_000u64);
+    group.bench_function("fast_list_iter_100k", |b| {
+        b.iter(|| fast_list.iter().map(|item| item.value).sum::<u64>())
+    });
+    group.bench_function("unrolled_list_iter_100k", |b| {
+        b.iter(|| unrolled_list.iter().map(|(_, value)| *value).sum::<u64>())
+    });
+    group.bench_function("vec_deque_iter_100k", |b| {
+        b.iter(|| vec_deque.iter().sum::<u64>())
+    });
+    group.finish();
+
+    // Insert after a pseudo random element that was inserted earlier.
+    fn positions() -> impl Iterator<Item = usize> {
+        (1..10_000usize).map(|i| i.wrapping_mul(7919) % i)
+    }
+    let mut group = c.benchmark_group("unrolled_random_insert_10k");
+    group.bench_function("fast_list_random_insert_10k", |b| {
+        b.iter(|| {
+            let mut list = FastLinkedList::new();
+            let mut indexes = vec![list.push_back(0)];
+            for (i, at) in positions().enumerate() {
+                indexes.push(list.insert_after(indexes[at], i));
+            }
+            list.len()
+        })
+    });
+    group.bench_function("unrolled_list_random_insert_10k", |b| {
+        b.iter(|| {
+            let mut list = UnrolledList::<usize>::new();
+            let mut indexes = vec![list.push_back(0)];
+            for (i, at) in positions().enumerate() {
+                indexes.push(list.insert_after(indexes[at], i));
+            }
+            list.len()
+        })
+    });
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_list_remove,
@@ -1110,7 +1243,10 @@ criterion_group!(
     bench_list_pop_front,
     bench_list_iter,
     bench_list_iter_reverse,
-    bench_list_split_off
+    bench_list_split_off,
+    bench_item_layout,
+    bench_forward_list,
+    bench_unrolled_list
 );
 
 criterion_main!(benches);
@@ -0,0 +1,333 @@
+use core::borrow::Borrow;
+use core::hash::Hash;
+
+use hashbrown::HashMap;
+
+use crate::cache::{CachePolicy, CacheStats, EvictionCallback};
+use crate::linked_list::{LinkedList, LinkedListIndex};
+
+/// The four lists of the ARC algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Segment {
+    /// Entries that were used once recently.
+    T1,
+    /// Entries that were used at least twice recently.
+    T2,
+    /// Ghosts of entries recently evicted from T1, without values.
+    B1,
+    /// Ghosts of entries recently evicted from T2, without values.
+    B2,
+}
+
+struct ArcEntry<V> {
+    segment: Segment,
+    node: LinkedListIndex,
+    /// None for ghost entries.
+    value: Option<V>,
+}
+
+/// An Adaptive Replacement Cache (Megiddo and Modha).
+///
+/// Keeps a recency list (T1) and a frequency list (T2) of cached entries, plus two ghost lists
+/// (B1, B2) of keys that were recently evicted from them. Hits on ghosts shift the target size
+/// of T1 towards the list that would have produced a hit, which makes the cache resistant to
+/// scans while still adapting to changes in the access pattern. All four lists are [`LinkedList`]s
+/// of keys, most recently used at the front.
+///
+/// # Example
+/// ```
+/// use fast_list::ArcCache;
+///
+/// let mut cache = ArcCache::new(2);
+/// cache.put(1, "one");
+/// cache.get(&1);
+///
+/// // A scan of keys that are only used once does not evict the frequently used key.
+/// for key in 2..10 {
+///     cache.put(key, "scan");
+/// }
+/// assert_eq!(cache.peek(&1), Some(&"one"));
+/// ```
+pub struct ArcCache<K, V> {
+    map: HashMap<K, ArcEntry<V>>,
+    t1: LinkedList<K>,
+    t2: LinkedList<K>,
+    b1: LinkedList<K>,
+    b2: LinkedList<K>,
+    /// The target size of T1.
+    p: usize,
+    cap: usize,
+    on_evict: Option<EvictionCallback<K, V>>,
+    stats: CacheStats,
+}
+
+impl<K: Hash + Eq + Clone, V> ArcCache<K, V> {
+    /// Create a new empty cache that holds at most `cap` entries (and remembers up to `cap` evicted keys).
+    pub fn new(cap: usize) -> Self {
+        Self {
+            map: HashMap::with_capacity(2 * cap),
+            t1: LinkedList::new(),
+            t2: LinkedList::new(),
+            b1: LinkedList::new(),
+            b2: LinkedList::new(),
+            p: 0,
+            cap,
+            on_evict: None,
+            stats: CacheStats::default(),
+        }
+    }
+
+    /// Call `f` with every entry that is evicted because the cache is full.
+    pub fn with_eviction_callback<F>(mut self, f: F) -> Self
+    where
+        F: FnMut(K, V) + Send + 'static,
+    {
+        self.on_evict = Some(Box::new(f));
+        self
+    }
+
+    /// The maximum number of entries.
+    pub fn cap(&self) -> usize {
+        self.cap
+    }
+
+    /// Get the number of entries (not counting ghosts).
+    pub fn len(&self) -> usize {
+        self.t1.len() + self.t2.len()
+    }
+
+    /// Returns true if the cache has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The hit and miss counters of [`get`](Self::get) and [`get_mut`](Self::get_mut).
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    /// The current target size of the recency list, between 0 and `cap`.
+    pub fn target_recency_size(&self) -> usize {
+        self.p
+    }
+
+    /// Returns true if the cache contains the key (ghosts do not count).
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.peek(key).is_some()
+    }
+
+    /// Get the value of a key and count it as a use.
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_mut(key).map(|value| &*value)
+    }
+
+    /// Get a mutable reference to the value of a key and count it as a use.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let hit = self.contains(key);
+        self.stats.record(hit);
+        if !hit {
+            return None;
+        }
+        self.promote(key);
+        self.map.get_mut(key).and_then(|entry| entry.value.as_mut())
+    }
+
+    /// Get the value of a key without counting it as a use.
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key).and_then(|entry| entry.value.as_ref())
+    }
+
+    /// Insert or update an entry, evicting another entry if the cache is full.
+    ///
+    /// Returns the old value if the key was already in the cache.
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        if self.cap == 0 {
+            self.evict(key, value);
+            return None;
+        }
+        let segment = self.map.get(&key).map(|entry| entry.segment);
+        match segment {
+            Some(Segment::T1 | Segment::T2) => {
+                self.promote(&key);
+                self.map.get_mut(&key).unwrap().value.replace(value)
+            }
+            Some(ghost @ (Segment::B1 | Segment::B2)) => {
+                // A ghost hit means the list it was evicted from should have been larger.
+                let (b1, b2) = (self.b1.len(), self.b2.len());
+                if ghost == Segment::B1 {
+                    self.p = (self.p + (b2 / b1).max(1)).min(self.cap);
+                } else {
+                    self.p = self.p.saturating_sub((b1 / b2).max(1));
+                }
+                if self.len() >= self.cap {
+                    self.replace(ghost == Segment::B2);
+                }
+                let node = self.map[&key].node;
+                self.list_mut(ghost).remove(node);
+                let node = self.t2.push_front(key.clone());
+                self.map.insert(
+                    key,
+                    ArcEntry {
+                        segment: Segment::T2,
+                        node,
+                        value: Some(value),
+                    },
+                );
+                None
+            }
+            None => {
+                let l1 = self.t1.len() + self.b1.len();
+                let total = l1 + self.t2.len() + self.b2.len();
+                if l1 >= self.cap {
+                    if self.t1.len() < self.cap {
+                        self.drop_ghost(Segment::B1);
+                        if self.len() >= self.cap {
+                            self.replace(false);
+                        }
+                    } else if let Some(old) = self.t1.pop_back() {
+                        let entry = self.map.remove(&old).unwrap();
+                        self.evict(old, entry.value.unwrap());
+                    }
+                } else if total >= self.cap {
+                    if total >= 2 * self.cap {
+                        self.drop_ghost(Segment::B2);
+                    }
+                    if self.len() >= self.cap {
+                        self.replace(false);
+                    }
+                }
+                let node = self.t1.push_front(key.clone());
+                self.map.insert(
+                    key,
+                    ArcEntry {
+                        segment: Segment::T1,
+                        node,
+                        value: Some(value),
+                    },
+                );
+                None
+            }
+        }
+    }
+
+    /// Remove an entry, returning its value if the key was in the cache. Ghosts of the key are forgotten too.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let entry = self.map.remove(key)?;
+        self.list_mut(entry.segment).remove(entry.node);
+        entry.value
+    }
+
+    /// Move a cached entry to the front of T2.
+    fn promote<Q>(&mut self, key: &Q)
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let entry = self.map.get_mut(key).unwrap();
+        match entry.segment {
+            Segment::T1 => {
+                let key = self.t1.remove(entry.node).unwrap().value;
+                entry.node = self.t2.push_front(key);
+                entry.segment = Segment::T2;
+            }
+            Segment::T2 => {
+                self.t2.move_to_front(entry.node);
+            }
+            Segment::B1 | Segment::B2 => unreachable!("ghosts are not cached"),
+        }
+    }
+
+    /// Evict the least recently used entry of T1 or T2 into its ghost list, depending on the target size.
+    ///
+    /// Only called while the cache is full, after a [`remove`](Self::remove) there is room without evicting.
+    fn replace(&mut self, hit_in_b2: bool) {
+        let t1 = self.t1.len();
+        let from_t1 = t1 > 0 && (t1 > self.p || (hit_in_b2 && t1 == self.p) || self.t2.is_empty());
+        let (from, to) = if from_t1 {
+            (Segment::T1, Segment::B1)
+        } else {
+            (Segment::T2, Segment::B2)
+        };
+        let Some(key) = self.list_mut(from).pop_back() else {
+            return;
+        };
+        let node = self.list_mut(to).push_front(key.clone());
+        let entry = self.map.get_mut(&key).unwrap();
+        entry.segment = to;
+        entry.node = node;
+        let value = entry.value.take().unwrap();
+        self.evict(key, value);
+    }
+
+    /// Forget the oldest ghost of B1 or B2.
+    fn drop_ghost(&mut self, ghost: Segment) {
+        if let Some(key) = self.list_mut(ghost).pop_back() {
+            self.map.remove(&key);
+        }
+    }
+
+    fn list_mut(&mut self, segment: Segment) -> &mut LinkedList<K> {
+        match segment {
+            Segment::T1 => &mut self.t1,
+            Segment::T2 => &mut self.t2,
+            Segment::B1 => &mut self.b1,
+            Segment::B2 => &mut self.b2,
+        }
+    }
+
+    fn evict(&mut self, key: K, value: V) {
+        if let Some(on_evict) = &mut self.on_evict {
+            on_evict(key, value);
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, V> CachePolicy<K, V> for ArcCache<K, V> {
+    fn get(&mut self, key: &K) -> Option<&V> {
+        ArcCache::get(self, key)
+    }
+
+    fn peek(&self, key: &K) -> Option<&V> {
+        ArcCache::peek(self, key)
+    }
+
+    fn put(&mut self, key: K, value: V) -> Option<V> {
+        ArcCache::put(self, key, value)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        ArcCache::remove(self, key)
+    }
+
+    fn len(&self) -> usize {
+        ArcCache::len(self)
+    }
+
+    fn cap(&self) -> usize {
+        ArcCache::cap(self)
+    }
+
+    fn stats(&self) -> CacheStats {
+        ArcCache::stats(self)
+    }
+}
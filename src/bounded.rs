@@ -0,0 +1,230 @@
+use core::fmt;
+use std::ops::Deref;
+use thiserror::Error;
+
+use crate::linked_list::{LinkedList, LinkedListIndex, LinkedListItem};
+
+/// Picks the item to evict from a full [`BoundedList`], or None to reject the new value.
+pub type OverflowCallback<T> = Box<dyn FnMut(&LinkedList<T>) -> Option<LinkedListIndex> + Send>;
+
+/// What a [`BoundedList`] does when a value is added while it is full.
+pub enum OverflowPolicy<T> {
+    /// Refuse the new value and hand it back in a [`CapacityError`].
+    Reject,
+    /// Evict the item at the front to make room.
+    EvictFront,
+    /// Evict the item at the back to make room.
+    EvictBack,
+    /// Let a callback pick the item to evict, it rejects the new value by returning None.
+    Callback(OverflowCallback<T>),
+}
+
+impl<T> fmt::Debug for OverflowPolicy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Reject => f.write_str("Reject"),
+            Self::EvictFront => f.write_str("EvictFront"),
+            Self::EvictBack => f.write_str("EvictBack"),
+            Self::Callback(_) => f.write_str("Callback"),
+        }
+    }
+}
+
+/// A value that did not fit in a full [`BoundedList`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("the list is full")]
+pub struct CapacityError<T> {
+    /// The rejected value.
+    pub value: T,
+}
+
+/// A [`LinkedList`] that holds at most `cap` items.
+///
+/// Adding a value to a full list applies the [`OverflowPolicy`]: the value is either rejected or
+/// another item is evicted first, in which case the evicted value is returned next to the index
+/// of the new item. The list can be read through `Deref`, but is only modified through the
+/// methods of `BoundedList` so the cap can't be bypassed.
+///
+/// # Example
+/// ```
+/// use fast_list::{BoundedList, OverflowPolicy};
+///
+/// let mut queue = BoundedList::new(2).with_policy(OverflowPolicy::EvictFront);
+/// queue.push_back("a").unwrap();
+/// queue.push_back("b").unwrap();
+/// let (_, evicted) = queue.push_back("c").unwrap();
+/// assert_eq!(evicted, Some("a"));
+/// assert_eq!(queue.iter().map(|item| item.value).collect::<Vec<_>>(), vec!["b", "c"]);
+///
+/// let mut strict = BoundedList::new(1);
+/// strict.push_back(1).unwrap();
+/// assert_eq!(strict.push_back(2).unwrap_err().value, 2);
+/// ```
+pub struct BoundedList<T> {
+    list: LinkedList<T>,
+    cap: usize,
+    policy: OverflowPolicy<T>,
+}
+
+impl<T: fmt::Debug> fmt::Debug for BoundedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoundedList")
+            .field("list", &self.list)
+            .field("cap", &self.cap)
+            .field("policy", &self.policy)
+            .finish()
+    }
+}
+
+impl<T> Deref for BoundedList<T> {
+    type Target = LinkedList<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl<T> BoundedList<T> {
+    /// Create a new empty list that holds at most `cap` items and rejects values when it is full.
+    pub fn new(cap: usize) -> Self {
+        Self {
+            list: LinkedList::new(),
+            cap,
+            policy: OverflowPolicy::Reject,
+        }
+    }
+
+    /// Set what happens when a value is added while the list is full.
+    pub fn with_policy(mut self, policy: OverflowPolicy<T>) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// The maximum number of items.
+    pub fn cap(&self) -> usize {
+        self.cap
+    }
+
+    /// Returns true if adding a value would overflow.
+    pub fn is_full(&self) -> bool {
+        self.list.len() >= self.cap
+    }
+
+    /// Return the underlying list.
+    pub fn into_inner(self) -> LinkedList<T> {
+        self.list
+    }
+
+    /// Get a mutable reference to an item.
+    pub fn get_mut(&mut self, index: LinkedListIndex) -> Option<&mut LinkedListItem<T>> {
+        self.list.get_mut(index)
+    }
+
+    /// Add a value to the back of the list.
+    ///
+    /// Returns the index of the new item and the value that was evicted to make room, if any.
+    pub fn push_back(
+        &mut self,
+        value: T,
+    ) -> Result<(LinkedListIndex, Option<T>), CapacityError<T>> {
+        let (value, evicted) = self.make_room(value)?;
+        Ok((self.list.push_back(value), evicted))
+    }
+
+    /// Add a value to the front of the list, see [`push_back`](Self::push_back).
+    pub fn push_front(
+        &mut self,
+        value: T,
+    ) -> Result<(LinkedListIndex, Option<T>), CapacityError<T>> {
+        let (value, evicted) = self.make_room(value)?;
+        Ok((self.list.push_front(value), evicted))
+    }
+
+    /// Insert a value after the given index, see [`push_back`](Self::push_back).
+    ///
+    /// If the index itself is evicted the value takes its place.
+    ///
+    /// # Panics
+    /// Panics if the index does not exist.
+    pub fn insert_after(
+        &mut self,
+        index: LinkedListIndex,
+        value: T,
+    ) -> Result<(LinkedListIndex, Option<T>), CapacityError<T>> {
+        let next = self
+            .list
+            .get(index)
+            .expect("index does not exist")
+            .next_index;
+        let (value, evicted) = self.make_room(value)?;
+        let new = if self.list.contains_key(index) {
+            self.list.insert_after(index, value)
+        } else {
+            match next {
+                Some(next) => self.list.insert_before(next, value),
+                None => self.list.push_back(value),
+            }
+        };
+        Ok((new, evicted))
+    }
+
+    /// Insert a value before the given index, see [`push_back`](Self::push_back).
+    ///
+    /// If the index itself is evicted the value takes its place.
+    ///
+    /// # Panics
+    /// Panics if the index does not exist.
+    pub fn insert_before(
+        &mut self,
+        index: LinkedListIndex,
+        value: T,
+    ) -> Result<(LinkedListIndex, Option<T>), CapacityError<T>> {
+        let prev = self
+            .list
+            .get(index)
+            .expect("index does not exist")
+            .prev_index;
+        let (value, evicted) = self.make_room(value)?;
+        let new = if self.list.contains_key(index) {
+            self.list.insert_before(index, value)
+        } else {
+            match prev {
+                Some(prev) => self.list.insert_after(prev, value),
+                None => self.list.push_front(value),
+            }
+        };
+        Ok((new, evicted))
+    }
+
+    /// Remove the last value in the list and return it (if it exists)
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+
+    /// Remove the first value in the list and return it (if it exists)
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    /// Remove an item from the list, returning it if the index was not previously removed.
+    pub fn remove(&mut self, index: LinkedListIndex) -> Option<LinkedListItem<T>> {
+        self.list.remove(index)
+    }
+
+    /// Apply the overflow policy if the list is full, returning the value back with the evicted one.
+    fn make_room(&mut self, value: T) -> Result<(T, Option<T>), CapacityError<T>> {
+        if !self.is_full() {
+            return Ok((value, None));
+        }
+        let victim = match &mut self.policy {
+            OverflowPolicy::Reject => None,
+            OverflowPolicy::EvictFront => self.list.head,
+            OverflowPolicy::EvictBack => self.list.tail,
+            OverflowPolicy::Callback(f) => f(&self.list),
+        };
+        match victim.and_then(|victim| self.list.remove(victim)) {
+            Some(evicted) => Ok((value, Some(evicted.value))),
+            None => Err(CapacityError { value }),
+        }
+    }
+}
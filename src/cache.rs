@@ -0,0 +1,66 @@
+/// A callback that receives entries evicted because a cache ran out of capacity.
+pub type EvictionCallback<K, V> = Box<dyn FnMut(K, V) + Send>;
+
+/// Hit and miss counters of a cache.
+///
+/// Only lookups through `get` (and `get_mut`) are counted, `peek` and `put` are not.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// The number of lookups that found the key.
+    pub hits: u64,
+    /// The number of lookups that did not find the key.
+    pub misses: u64,
+}
+
+impl CacheStats {
+    /// The fraction of lookups that found the key, or 0 if there were no lookups.
+    pub fn hit_ratio(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+
+    pub(crate) fn record(&mut self, hit: bool) {
+        if hit {
+            self.hits += 1;
+        } else {
+            self.misses += 1;
+        }
+    }
+}
+
+/// The interface shared by the caches in this crate ([`LruCache`](crate::LruCache),
+/// [`LfuCache`](crate::LfuCache) and [`ArcCache`](crate::ArcCache)), so they can be swapped
+/// and compared with each other.
+pub trait CachePolicy<K, V> {
+    /// Get the value of a key, counting the lookup as a hit or a miss and updating the eviction order.
+    fn get(&mut self, key: &K) -> Option<&V>;
+
+    /// Get the value of a key without changing anything.
+    fn peek(&self, key: &K) -> Option<&V>;
+
+    /// Insert or update an entry, evicting another entry if the cache is full.
+    ///
+    /// Returns the old value if the key was already in the cache.
+    fn put(&mut self, key: K, value: V) -> Option<V>;
+
+    /// Remove an entry, returning its value if the key was in the cache.
+    fn remove(&mut self, key: &K) -> Option<V>;
+
+    /// Get the number of entries.
+    fn len(&self) -> usize;
+
+    /// Returns true if the cache has no entries.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The maximum number of entries.
+    fn cap(&self) -> usize;
+
+    /// The hit and miss counters.
+    fn stats(&self) -> CacheStats;
+}
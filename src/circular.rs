@@ -0,0 +1,217 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::linked_list::{ItemRef, LinkedList, LinkedListIndex};
+
+/// A [`LinkedList`] that is traversed as a ring: the item after the tail is the head and the item before the head is the tail.
+///
+/// The underlying list stays linear, `head` is simply the current start of the ring, so every
+/// [`LinkedList`] method keeps working through `Deref`/`DerefMut`. Moving the start of the ring
+/// with [`set_head`](Self::set_head) relinks the old tail to the old head, which is O(1).
+///
+/// # Example
+/// ```
+/// use fast_list::CircularList;
+///
+/// let mut ring = CircularList::new();
+/// let tasks = ring.extend(["a", "b", "c"]);
+///
+/// // Round robin: serve the head, then rotate it to the back.
+/// let mut served = Vec::new();
+/// for _ in 0..4 {
+///     served.push(ring.head().unwrap().value);
+///     ring.rotate_forward(1);
+/// }
+/// assert_eq!(served, vec!["a", "b", "c", "a"]);
+///
+/// let from_c = ring.iter_from(tasks[2]).map(|item| item.value).collect::<Vec<_>>();
+/// assert_eq!(from_c, vec!["c", "a", "b"]);
+/// ```
+#[derive(Debug)]
+pub struct CircularList<T> {
+    list: LinkedList<T>,
+}
+
+impl<T> Default for CircularList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<LinkedList<T>> for CircularList<T> {
+    /// Treat an existing list as a ring that starts at its head.
+    fn from(list: LinkedList<T>) -> Self {
+        Self { list }
+    }
+}
+
+impl<T> Deref for CircularList<T> {
+    type Target = LinkedList<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl<T> DerefMut for CircularList<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.list
+    }
+}
+
+impl<T> CircularList<T> {
+    /// Create a new empty ring.
+    pub fn new() -> Self {
+        Self::from(LinkedList::new())
+    }
+
+    /// Return the underlying list, which starts at the current head of the ring.
+    pub fn into_inner(self) -> LinkedList<T> {
+        self.list
+    }
+
+    /// Returns the index of the item after the given one, wrapping from the tail to the head.
+    ///
+    /// Returns None if the index does not exist.
+    pub fn next(&self, index: LinkedListIndex) -> Option<LinkedListIndex> {
+        let item = self.list.get(index)?;
+        item.next_index.or(self.list.head)
+    }
+
+    /// Returns the index of the item before the given one, wrapping from the head to the tail.
+    ///
+    /// Returns None if the index does not exist.
+    pub fn prev(&self, index: LinkedListIndex) -> Option<LinkedListIndex> {
+        let item = self.list.get(index)?;
+        item.prev_index.or(self.list.tail)
+    }
+
+    /// Make the given item the head of the ring without changing the order of the ring.
+    /// This operation should compute in O(1) time.
+    ///
+    /// If events are enabled the items on the shorter side of the new head are moved across one
+    /// at a time instead, logging a [`Move`](crate::ListEventKind::Move) for each, so the log can be
+    /// replayed. That takes O(min(k, len - k)) time, where `k` is the position of the new head.
+    ///
+    /// Returns false if the index does not exist.
+    pub fn set_head(&mut self, index: LinkedListIndex) -> bool {
+        let Some(item) = self.list.get(index) else {
+            return false;
+        };
+        let Some(prev) = item.prev_index else {
+            // Already the head.
+            return true;
+        };
+        let (head, tail) = (self.list.head.unwrap(), self.list.tail.unwrap());
+
+        if self.list.events_enabled() {
+            self.rotate_by_moves(index, prev);
+            return true;
+        }
+
+        self.list.get_mut(tail).unwrap().next_index = Some(head);
+        self.list.get_mut(head).unwrap().prev_index = Some(tail);
+        self.list.get_mut(prev).unwrap().next_index = None;
+        self.list.get_mut(index).unwrap().prev_index = None;
+        self.list.head = Some(index);
+        self.list.tail = Some(prev);
+        true
+    }
+
+    /// Make `index` (whose previous item is `prev`) the head by moving the items in front of it
+    /// to the back, or the items from it onwards to the front, whichever are fewer.
+    fn rotate_by_moves(&mut self, index: LinkedListIndex, prev: LinkedListIndex) {
+        // Walk in from both ends at once to find the shorter side without counting the whole ring.
+        let (mut front, mut back) = (self.list.head.unwrap(), self.list.tail.unwrap());
+        let move_front = loop {
+            if front == prev {
+                break true;
+            }
+            if back == index {
+                break false;
+            }
+            front = self.list.cursor_next(front).unwrap();
+            back = self.list.cursor_prev(back).unwrap();
+        };
+
+        while self.list.head != Some(index) {
+            let (head, tail) = (self.list.head.unwrap(), self.list.tail.unwrap());
+            if move_front {
+                self.list.move_after(head, tail);
+            } else {
+                self.list.move_before(tail, head);
+            }
+        }
+    }
+
+    /// Rotate the ring so that the item `n` steps after the current head becomes the head.
+    /// This operation should compute in O(min(n, len - n)) time, after reducing `n` modulo the length.
+    pub fn rotate_forward(&mut self, n: usize) {
+        let len = self.list.len();
+        if len == 0 {
+            return;
+        }
+        let n = n % len;
+        if n == 0 {
+            return;
+        }
+        let head = if n <= len / 2 {
+            self.cursor_iter_from(self.list.head.unwrap()).nth(n)
+        } else {
+            self.cursor_iter_back_from(self.list.head.unwrap())
+                .nth(len - n)
+        };
+        self.set_head(head.unwrap());
+    }
+
+    /// Rotate the ring so that the item `n` steps before the current head becomes the head.
+    /// This operation should compute in O(min(n, len - n)) time, after reducing `n` modulo the length.
+    pub fn rotate_backward(&mut self, n: usize) {
+        let len = self.list.len();
+        if len == 0 {
+            return;
+        }
+        self.rotate_forward(len - n % len);
+    }
+
+    /// Returns an iterator over the indexes of the ring starting at `start` and wrapping around,
+    /// visiting every item exactly once.
+    ///
+    /// The iterator is empty if the index does not exist.
+    pub fn cursor_iter_from(
+        &self,
+        start: LinkedListIndex,
+    ) -> impl Iterator<Item = LinkedListIndex> + '_ {
+        let first = Some(start).filter(|start| self.list.contains_key(*start));
+        std::iter::successors(first, move |index| {
+            self.next(*index).filter(|next| *next != start)
+        })
+    }
+
+    /// Returns an iterator over the indexes of the ring starting at `start` and wrapping around
+    /// backwards, visiting every item exactly once.
+    ///
+    /// The iterator is empty if the index does not exist.
+    pub fn cursor_iter_back_from(
+        &self,
+        start: LinkedListIndex,
+    ) -> impl Iterator<Item = LinkedListIndex> + '_ {
+        let first = Some(start).filter(|start| self.list.contains_key(*start));
+        std::iter::successors(first, move |index| {
+            self.prev(*index).filter(|prev| *prev != start)
+        })
+    }
+
+    /// Returns an iterator over the items of the ring starting at `start` and wrapping around,
+    /// visiting every item exactly once.
+    pub fn iter_from(&self, start: LinkedListIndex) -> impl Iterator<Item = ItemRef<'_, T>> {
+        self.cursor_iter_from(start)
+            .map(move |index| self.list.item_ref(index))
+    }
+
+    /// Returns an iterator over the items of the ring starting at `start` and wrapping around
+    /// backwards, visiting every item exactly once.
+    pub fn iter_back_from(&self, start: LinkedListIndex) -> impl Iterator<Item = ItemRef<'_, T>> {
+        self.cursor_iter_back_from(start)
+            .map(move |index| self.list.item_ref(index))
+    }
+}
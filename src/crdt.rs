@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+
+use crate::linked_list::{LinkedList, LinkedListIndex};
+
+/// The globally unique id of an element in a [`RgaList`].
+///
+/// Ids are ordered by their Lamport `counter` first and by `site` second,
+/// which is the order concurrent inserts at the same position end up in (greatest first).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RgaId {
+    /// The Lamport timestamp of the insert.
+    pub counter: u64,
+    /// The replica that made the insert.
+    pub site: u32,
+}
+
+/// An operation produced by a local edit of a [`RgaList`] that has to be sent to the other replicas.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RgaOp<T> {
+    /// Insert `value` with the given `id` right after the element `after` (or at the start if None).
+    Insert {
+        id: RgaId,
+        after: Option<RgaId>,
+        value: T,
+    },
+    /// Delete the element with the given id.
+    Delete { id: RgaId },
+}
+
+#[derive(Debug)]
+struct RgaNode<T> {
+    id: RgaId,
+    value: T,
+    /// Deleted elements are kept as tombstones because remote inserts might still refer to them.
+    deleted: bool,
+}
+
+/// A Replicated Growable Array, a sequence CRDT for collaborative editing.
+///
+/// Every replica has its own `site` id. Local edits return an [`RgaOp`] which has to be
+/// delivered to every other replica with [`apply`](Self::apply). Replicas that have applied
+/// the same set of operations contain the same elements in the same order, no matter in which
+/// order the operations arrived. Operations that arrive before the operations they depend on
+/// are buffered until they can be applied, and applying an operation twice does nothing.
+///
+/// # Example
+/// ```
+/// use fast_list::RgaList;
+///
+/// let mut alice = RgaList::new(1);
+/// let mut bob = RgaList::new(2);
+///
+/// let op = alice.insert(0, 'a');
+/// bob.apply(op);
+///
+/// // Both insert at the end at the same time.
+/// let from_alice = alice.insert(1, 'b');
+/// let from_bob = bob.insert(1, 'c');
+/// alice.apply(from_bob);
+/// bob.apply(from_alice);
+///
+/// assert_eq!(alice.to_vec(), bob.to_vec());
+/// ```
+pub struct RgaList<T> {
+    site: u32,
+    /// The Lamport clock of this replica.
+    counter: u64,
+    /// All elements, including tombstones, in document order.
+    list: LinkedList<RgaNode<T>>,
+    ids: HashMap<RgaId, LinkedListIndex>,
+    /// Remote operations that depend on elements this replica has not seen yet.
+    pending: Vec<RgaOp<T>>,
+    /// The number of elements that are not deleted.
+    visible: usize,
+}
+
+impl<T> RgaList<T> {
+    /// Create a new empty replica with the given site id. Every replica needs a different site id.
+    pub fn new(site: u32) -> Self {
+        Self {
+            site,
+            counter: 0,
+            list: LinkedList::new(),
+            ids: HashMap::new(),
+            pending: Vec::new(),
+            visible: 0,
+        }
+    }
+
+    /// The site id of this replica.
+    pub fn site(&self) -> u32 {
+        self.site
+    }
+
+    /// Get the number of elements that are not deleted.
+    pub fn len(&self) -> usize {
+        self.visible
+    }
+
+    /// Returns true if there are no elements that are not deleted.
+    pub fn is_empty(&self) -> bool {
+        self.visible == 0
+    }
+
+    /// Get the number of remote operations that are waiting for operations they depend on.
+    pub fn pending_len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Get the value of an element if it exists and is not deleted.
+    pub fn get(&self, id: RgaId) -> Option<&T> {
+        let node = &self.list.get(*self.ids.get(&id)?)?.value;
+        (!node.deleted).then_some(&node.value)
+    }
+
+    /// Get the id of the element at the given position, not counting deleted elements.
+    pub fn id_at(&self, position: usize) -> Option<RgaId> {
+        self.visible_indexes()
+            .nth(position)
+            .map(|index| self.list.get(index).unwrap().value.id)
+    }
+
+    /// Returns an iterator over the elements that are not deleted, in order.
+    pub fn iter(&self) -> impl Iterator<Item = (RgaId, &T)> {
+        self.visible_indexes().map(move |index| {
+            let node = &self.list.get(index).unwrap().value;
+            (node.id, &node.value)
+        })
+    }
+
+    /// Delete the element at the given position, not counting deleted elements.
+    ///
+    /// Returns None if the position is out of bounds.
+    pub fn delete(&mut self, position: usize) -> Option<RgaOp<T>> {
+        let id = self.id_at(position)?;
+        self.integrate_delete(id);
+        Some(RgaOp::Delete { id })
+    }
+
+    /// Apply an operation from another replica (or replay one of our own, which does nothing).
+    pub fn apply(&mut self, op: RgaOp<T>) {
+        if let Some(op) = self.try_integrate(op) {
+            self.pending.push(op);
+            return;
+        }
+        // Applying an operation can make buffered operations applicable, repeat until nothing changes.
+        loop {
+            let before = self.pending.len();
+            for op in std::mem::take(&mut self.pending) {
+                if let Some(op) = self.try_integrate(op) {
+                    self.pending.push(op);
+                }
+            }
+            if self.pending.len() == before {
+                break;
+            }
+        }
+    }
+
+    /// Apply many operations, see [`apply`](Self::apply).
+    pub fn apply_all<I>(&mut self, ops: I)
+    where
+        I: IntoIterator<Item = RgaOp<T>>,
+    {
+        for op in ops {
+            self.apply(op);
+        }
+    }
+
+    fn visible_indexes(&self) -> impl Iterator<Item = LinkedListIndex> + '_ {
+        self.list
+            .head
+            .into_iter()
+            .flat_map(move |head| self.list.cursor_iter_next(head))
+            .filter(move |index| !self.list.get(*index).unwrap().value.deleted)
+    }
+
+    fn next_id(&mut self) -> RgaId {
+        self.counter += 1;
+        RgaId {
+            counter: self.counter,
+            site: self.site,
+        }
+    }
+
+    /// Apply an operation if everything it depends on is known, otherwise hand it back.
+    fn try_integrate(&mut self, op: RgaOp<T>) -> Option<RgaOp<T>> {
+        match op {
+            RgaOp::Insert { id, after, value } => {
+                if let Some(missing) = after.filter(|after| !self.ids.contains_key(after)) {
+                    return Some(RgaOp::Insert {
+                        id,
+                        after: Some(missing),
+                        value,
+                    });
+                }
+                self.integrate_insert(id, after, value);
+            }
+            RgaOp::Delete { id } => {
+                if !self.ids.contains_key(&id) {
+                    return Some(RgaOp::Delete { id });
+                }
+                self.integrate_delete(id);
+            }
+        }
+        None
+    }
+
+    fn integrate_insert(&mut self, id: RgaId, after: Option<RgaId>, value: T) {
+        if self.ids.contains_key(&id) {
+            return;
+        }
+        self.counter = self.counter.max(id.counter);
+
+        // Skip over elements that were inserted at the same position with a greater id,
+        // together with everything that was inserted after them.
+        let mut prev = after.map(|after| self.ids[&after]);
+        let mut candidate = match prev {
+            Some(prev) => self.list.cursor_next(prev),
+            None => self.list.head,
+        };
+        while let Some(index) = candidate {
+            if self.list.get(index).unwrap().value.id < id {
+                break;
+            }
+            prev = Some(index);
+            candidate = self.list.cursor_next(index);
+        }
+
+        let node = RgaNode {
+            id,
+            value,
+            deleted: false,
+        };
+        let index = match prev {
+            Some(prev) => self.list.insert_after(prev, node),
+            None => self.list.push_front(node),
+        };
+        self.ids.insert(id, index);
+        self.visible += 1;
+    }
+
+    fn integrate_delete(&mut self, id: RgaId) {
+        let node = &mut self.list.get_mut(self.ids[&id]).unwrap().value;
+        if !node.deleted {
+            node.deleted = true;
+            self.visible -= 1;
+        }
+    }
+}
+
+impl<T: Clone> RgaList<T> {
+    /// Insert a value at the given position, not counting deleted elements.
+    ///
+    /// # Panics
+    /// Panics if `position > len`.
+    pub fn insert(&mut self, position: usize, value: T) -> RgaOp<T> {
+        assert!(position <= self.visible, "insertion position out of bounds");
+        let after = position.checked_sub(1).map(|i| self.id_at(i).unwrap());
+        self.insert_after(after, value)
+    }
+
+    /// Insert a value right after the element with the given id (or at the start if None).
+    ///
+    /// The element does not have to be visible, inserting after a deleted element is fine.
+    pub fn insert_after(&mut self, after: Option<RgaId>, value: T) -> RgaOp<T> {
+        if let Some(after) = after {
+            assert!(self.ids.contains_key(&after), "unknown element id");
+        }
+        let id = self.next_id();
+        self.integrate_insert(id, after, value.clone());
+        RgaOp::Insert { id, after, value }
+    }
+
+    /// Copy the values that are not deleted into a Vec, in order.
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().map(|(_, value)| value.clone()).collect()
+    }
+}
@@ -0,0 +1,240 @@
+use thiserror::Error;
+
+use crate::linked_list::{LinkedList, LinkedListIndex};
+
+/// A single step of an edit script produced by [`diff`].
+///
+/// The steps walk over the old list from the front, so applying them in order with
+/// [`apply_patch`] turns the old list into the new one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ListEdit<T> {
+    /// Keep the next `n` items.
+    Keep(usize),
+    /// Remove the next `n` items.
+    Remove(usize),
+    /// Insert a value before the next item.
+    Insert(T),
+}
+
+/// The reason an edit script could not be applied by [`apply_patch`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum PatchError {
+    /// The edit script keeps or removes more items than the list has.
+    #[error("the patch covers {needed} items but the list only has {len}")]
+    OutOfBounds { needed: usize, len: usize },
+}
+
+/// Computes a minimal edit script that turns `a` into `b` using Myers' diff algorithm.
+///
+/// This operation computes in O((N + M) * D) time, where D is the number of inserted and removed items.
+///
+/// # Example
+/// ```
+/// use fast_list::{apply_patch, diff, LinkedList, ListEdit};
+///
+/// let mut a = LinkedList::new();
+/// a.extend("kitten".chars());
+/// let mut b = LinkedList::new();
+/// b.extend("sitting".chars());
+///
+/// let edits = diff(&a, &b);
+/// assert_eq!(edits[0], ListEdit::Remove(1));
+/// assert_eq!(edits[1], ListEdit::Insert('s'));
+///
+/// let kept = a.nth(1).unwrap();
+/// apply_patch(&mut a, edits).unwrap();
+/// assert_eq!(a.iter().map(|item| item.value).collect::<String>(), "sitting");
+/// // Unchanged items keep their index.
+/// assert_eq!(a.nth(1), Some(kept));
+/// ```
+pub fn diff<T>(a: &LinkedList<T>, b: &LinkedList<T>) -> Vec<ListEdit<T>>
+where
+    T: PartialEq + Clone,
+{
+    let a = values(a);
+    let b = values(b);
+
+    let mut edits: Vec<ListEdit<T>> = Vec::new();
+    for step in shortest_edit(&a, &b) {
+        match (step, edits.last_mut()) {
+            (Step::Keep, Some(ListEdit::Keep(n))) | (Step::Remove, Some(ListEdit::Remove(n))) => {
+                *n += 1
+            }
+            (Step::Keep, _) => edits.push(ListEdit::Keep(1)),
+            (Step::Remove, _) => edits.push(ListEdit::Remove(1)),
+            (Step::Insert(i), _) => edits.push(ListEdit::Insert(b[i].clone())),
+        }
+    }
+    edits
+}
+
+/// Applies an edit script (usually produced by [`diff`]) to a list.
+///
+/// Items are inserted with [`LinkedList::insert_after`] and removed with [`LinkedList::remove`],
+/// so every item that is kept keeps its index. Items after the end of the script are kept.
+///
+/// The script is checked before anything is changed, so the list is untouched if this returns an error.
+pub fn apply_patch<T, I>(list: &mut LinkedList<T>, edits: I) -> Result<(), PatchError>
+where
+    I: IntoIterator<Item = ListEdit<T>>,
+{
+    let edits: Vec<ListEdit<T>> = edits.into_iter().collect();
+    let needed = edits
+        .iter()
+        .map(|edit| match edit {
+            ListEdit::Keep(n) | ListEdit::Remove(n) => *n,
+            ListEdit::Insert(_) => 0,
+        })
+        .sum();
+    if needed > list.len() {
+        return Err(PatchError::OutOfBounds {
+            needed,
+            len: list.len(),
+        });
+    }
+
+    // The last item that is part of the result so far, and the next item of the old list.
+    let mut prev: Option<LinkedListIndex> = None;
+    let mut next = list.head;
+    for edit in edits {
+        match edit {
+            ListEdit::Keep(n) => {
+                for _ in 0..n {
+                    prev = next;
+                    next = next.and_then(|index| list.cursor_next(index));
+                }
+            }
+            ListEdit::Remove(n) => {
+                for _ in 0..n {
+                    let index = next.unwrap();
+                    next = list.cursor_next(index);
+                    list.remove(index);
+                }
+            }
+            ListEdit::Insert(value) => {
+                prev = Some(match prev {
+                    Some(prev) => list.insert_after(prev, value),
+                    None => list.push_front(value),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+fn values<T>(list: &LinkedList<T>) -> Vec<&T> {
+    match list.head {
+        Some(head) => list.iter_next(head).map(|item| item.value()).collect(),
+        None => Vec::new(),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Step {
+    Keep,
+    Remove,
+    /// Insert the item of the new list at this position.
+    Insert(usize),
+}
+
+/// Myers' algorithm in linear space, returns the steps of the shortest edit script from front to back.
+///
+/// Instead of keeping a snapshot of the furthest reaching paths for every edit distance, this
+/// finds the middle snake of an optimal path and recurses on both sides of it, so it needs
+/// O(N + M) memory next to the result.
+fn shortest_edit<T: PartialEq>(a: &[&T], b: &[&T]) -> Vec<Step> {
+    let mut steps = Vec::with_capacity(a.len().max(b.len()));
+    edit_between(a, b, 0, &mut steps);
+    steps
+}
+
+/// Push the steps that turn `a` into `b`, where `b` starts at `b_start` in the new list.
+fn edit_between<T: PartialEq>(a: &[&T], b: &[&T], b_start: usize, steps: &mut Vec<Step>) {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let (a, b) = (&a[prefix..], &b[prefix..]);
+    let suffix = a
+        .iter()
+        .rev()
+        .zip(b.iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (a, b) = (&a[..a.len() - suffix], &b[..b.len() - suffix]);
+    let b_start = b_start + prefix;
+
+    steps.extend(std::iter::repeat_n(Step::Keep, prefix));
+    if a.is_empty() {
+        steps.extend((b_start..b_start + b.len()).map(Step::Insert));
+    } else if b.is_empty() {
+        steps.extend(std::iter::repeat_n(Step::Remove, a.len()));
+    } else {
+        // Both ends differ and neither side is empty, so the edit distance is at least 2 and
+        // both halves around the middle snake have a smaller one.
+        let (x, y, u, v) = middle_snake(a, b);
+        edit_between(&a[..x], &b[..y], b_start, steps);
+        steps.extend(std::iter::repeat_n(Step::Keep, u - x));
+        edit_between(&a[u..], &b[v..], b_start + v, steps);
+    }
+    steps.extend(std::iter::repeat_n(Step::Keep, suffix));
+}
+
+/// Find the middle snake of a shortest edit path from `a` to `b`, returns its start and end as
+/// `(x, y, u, v)`: the items `a[x..u]` and `b[y..v]` are kept.
+///
+/// Runs the greedy search from the front and from the back at the same time until they overlap.
+fn middle_snake<T: PartialEq>(a: &[&T], b: &[&T]) -> (usize, usize, usize, usize) {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let delta = n - m;
+    let odd = delta % 2 != 0;
+    let max = (n + m + 1) / 2;
+    // `forward[k + offset]` is the furthest x reached on diagonal k from the front,
+    // `backward[k + offset]` is the same for the reversed lists.
+    let offset = max + 1;
+    let mut forward = vec![0isize; 2 * offset as usize + 1];
+    let mut backward = vec![0isize; 2 * offset as usize + 1];
+    let at = |k: isize| (k + offset) as usize;
+
+    for d in 0..=max {
+        for k in (-d..=d).step_by(2) {
+            let start_x = if k == -d || (k != d && forward[at(k - 1)] < forward[at(k + 1)]) {
+                forward[at(k + 1)]
+            } else {
+                forward[at(k - 1)] + 1
+            };
+            let start_y = start_x - k;
+            let (mut x, mut y) = (start_x, start_y);
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            forward[at(k)] = x;
+            let back_k = delta - k;
+            if odd && (-(d - 1)..=d - 1).contains(&back_k) && x + backward[at(back_k)] >= n {
+                return (start_x as usize, start_y as usize, x as usize, y as usize);
+            }
+        }
+        for k in (-d..=d).step_by(2) {
+            let start_x = if k == -d || (k != d && backward[at(k - 1)] < backward[at(k + 1)]) {
+                backward[at(k + 1)]
+            } else {
+                backward[at(k - 1)] + 1
+            };
+            let start_y = start_x - k;
+            let (mut x, mut y) = (start_x, start_y);
+            while x < n && y < m && a[(n - 1 - x) as usize] == b[(m - 1 - y) as usize] {
+                x += 1;
+                y += 1;
+            }
+            backward[at(k)] = x;
+            let forward_k = delta - k;
+            if !odd && (-d..=d).contains(&forward_k) && x + forward[at(forward_k)] >= n {
+                return (
+                    (n - x) as usize,
+                    (m - y) as usize,
+                    (n - start_x) as usize,
+                    (m - start_y) as usize,
+                );
+            }
+        }
+    }
+    unreachable!("the searches from both ends always meet")
+}
@@ -0,0 +1,115 @@
+use crate::linked_list::{LinkedList, LinkedListIndex};
+
+/// A mutable handle to a single item of a [`LinkedList`], see [`LinkedList::entry`].
+///
+/// The entry borrows the list mutably, so the item can be read, changed, surrounded with new
+/// items or removed without looking it up again or juggling borrows in between.
+///
+/// # Example
+/// ```
+/// use fast_list::LinkedList;
+///
+/// let mut list = LinkedList::new();
+/// let indexes = list.extend([1, 2, 3, 4]);
+///
+/// // Walk the list, splitting even values in two halves and dropping the odd ones.
+/// let mut entry = list.entry(indexes[0]);
+/// while let Some(mut current) = entry {
+///     if *current.value() % 2 == 0 {
+///         let half = *current.value() / 2;
+///         *current.value_mut() = half;
+///         current.insert_after(half);
+///         entry = current.next().and_then(|inserted| inserted.next());
+///     } else {
+///         let next = current.next_index();
+///         current.remove();
+///         entry = next.and_then(|next| list.entry(next));
+///     }
+/// }
+/// assert_eq!(list.iter().map(|item| item.value).collect::<Vec<_>>(), vec![1, 1, 2, 2]);
+/// ```
+#[derive(Debug)]
+pub struct NodeEntry<'a, T> {
+    list: &'a mut LinkedList<T>,
+    index: LinkedListIndex,
+}
+
+impl<T> LinkedList<T> {
+    /// Get a mutable handle to an item, or None if the index does not exist.
+    pub fn entry(&mut self, index: LinkedListIndex) -> Option<NodeEntry<'_, T>> {
+        if !self.contains_key(index) {
+            return None;
+        }
+        Some(NodeEntry { list: self, index })
+    }
+}
+
+impl<'a, T> NodeEntry<'a, T> {
+    /// The index of the item.
+    pub fn index(&self) -> LinkedListIndex {
+        self.index
+    }
+
+    /// The index of the next item.
+    pub fn next_index(&self) -> Option<LinkedListIndex> {
+        self.list.get(self.index).unwrap().next_index
+    }
+
+    /// The index of the previous item.
+    pub fn prev_index(&self) -> Option<LinkedListIndex> {
+        self.list.get(self.index).unwrap().prev_index
+    }
+
+    /// Get the value of the item.
+    pub fn value(&self) -> &T {
+        &self.list.get(self.index).unwrap().value
+    }
+
+    /// Get a mutable reference to the value of the item.
+    pub fn value_mut(&mut self) -> &mut T {
+        &mut self.list.get_mut(self.index).unwrap().value
+    }
+
+    /// Convert the entry into a mutable reference to the value that lives as long as the list borrow.
+    pub fn into_value_mut(self) -> &'a mut T {
+        &mut self.list.get_mut(self.index).unwrap().value
+    }
+
+    /// Replace the value of the item and return the old one.
+    pub fn replace(&mut self, value: T) -> T {
+        self.list.replace(self.index, value).unwrap()
+    }
+
+    /// Insert a value after the item and return its index, the entry stays on the item.
+    pub fn insert_after(&mut self, value: T) -> LinkedListIndex {
+        self.list.insert_after(self.index, value)
+    }
+
+    /// Insert a value before the item and return its index, the entry stays on the item.
+    pub fn insert_before(&mut self, value: T) -> LinkedListIndex {
+        self.list.insert_before(self.index, value)
+    }
+
+    /// Move the entry to the next item, or return None if this is the tail.
+    pub fn next(self) -> Option<Self> {
+        let index = self.next_index()?;
+        Some(Self {
+            list: self.list,
+            index,
+        })
+    }
+
+    /// Move the entry to the previous item, or return None if this is the head.
+    pub fn prev(self) -> Option<Self> {
+        let index = self.prev_index()?;
+        Some(Self {
+            list: self.list,
+            index,
+        })
+    }
+
+    /// Remove the item and return its value, consuming the entry.
+    pub fn remove(self) -> T {
+        self.list.remove(self.index).unwrap().value
+    }
+}
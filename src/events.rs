@@ -0,0 +1,28 @@
+use crate::linked_list::LinkedListIndex;
+
+/// What kind of change a [`ListEvent`] describes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ListEventKind {
+    /// A new item was added to the list.
+    Insert,
+    /// An item was removed from the list.
+    Remove,
+    /// An item was moved to a different position, keeping its index.
+    Move,
+    /// The value of an item was changed.
+    Update,
+}
+
+/// A change made to a [`LinkedList`](crate::LinkedList) while events are enabled,
+/// see [`LinkedList::enable_events`](crate::LinkedList::enable_events).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ListEvent {
+    /// What happened to the item.
+    pub kind: ListEventKind,
+    /// The index of the item that changed.
+    pub index: LinkedListIndex,
+    /// The previous item right after the change, or right before it for [`ListEventKind::Remove`].
+    pub prev: Option<LinkedListIndex>,
+    /// The next item right after the change, or right before it for [`ListEventKind::Remove`].
+    pub next: Option<LinkedListIndex>,
+}
@@ -0,0 +1,282 @@
+use core::fmt;
+use core::ops::Deref;
+use slotmap::{new_key_type, SecondaryMap, SlotMap};
+
+new_key_type! {
+    /// A newtype for the index of an item in a [`ForwardList`].
+    pub struct ForwardListIndex;
+}
+
+#[derive(Debug)]
+pub struct ForwardListItem<T> {
+    /// The value of the item.
+    pub value: T,
+    /// The index of the next item in the list.
+    pub next_index: Option<ForwardListIndex>,
+}
+
+/// A view of an item together with its index, as yielded by the iterators of [`ForwardList`].
+///
+/// The counterpart of [`ItemRef`](crate::ItemRef) for the forward list. Derefs to the
+/// [`ForwardListItem`], so `item.value` and `item.next_index` work as usual.
+#[derive(Debug)]
+pub struct ForwardItemRef<'a, T> {
+    /// The index of the item in the list.
+    pub index: ForwardListIndex,
+    item: &'a ForwardListItem<T>,
+}
+
+impl<'a, T> Clone for ForwardItemRef<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for ForwardItemRef<'a, T> {}
+
+impl<'a, T> Deref for ForwardItemRef<'a, T> {
+    type Target = ForwardListItem<T>;
+
+    fn deref(&self) -> &Self::Target {
+        self.item
+    }
+}
+
+impl<'a, T> ForwardItemRef<'a, T> {
+    /// The item, borrowed for as long as the list.
+    pub fn item(&self) -> &'a ForwardListItem<T> {
+        self.item
+    }
+
+    /// The value of the item, borrowed for as long as the list.
+    pub fn value(&self) -> &'a T {
+        &self.item.value
+    }
+}
+
+impl<'a, T> From<ForwardItemRef<'a, T>> for (ForwardListIndex, &'a T) {
+    fn from(item: ForwardItemRef<'a, T>) -> Self {
+        (item.index, &item.item.value)
+    }
+}
+
+/// A singly linked list using SlotMap, for queues and lists that are only ever walked forward.
+///
+/// Every item only stores a link to the next item, so it takes one key (8 bytes) of overhead
+/// instead of the two a [`LinkedList`](crate::LinkedList) item needs. The price is that only the
+/// item *after* a known index can be removed in O(1), there is no `remove(index)` or `pop_back`.
+///
+/// # Example
+/// ```
+/// use fast_list::ForwardList;
+///
+/// let mut list = ForwardList::new();
+/// let a = list.push_back(1);
+/// list.push_back(3);
+/// list.insert_after(a, 2);
+/// assert_eq!(list.iter().map(|item| item.value).collect::<Vec<_>>(), vec![1, 2, 3]);
+///
+/// assert_eq!(list.remove_after(a), Some(2));
+/// assert_eq!(list.pop_front(), Some(1));
+/// assert_eq!(list.pop_front(), Some(3));
+/// assert!(list.is_empty());
+/// ```
+pub struct ForwardList<T = ()> {
+    /// The index of the first item in the list.
+    pub head: Option<ForwardListIndex>,
+    /// The index of the last item in the list.
+    pub tail: Option<ForwardListIndex>,
+    /// The items in the list.
+    items: SlotMap<ForwardListIndex, ForwardListItem<T>>,
+}
+
+impl<T: fmt::Debug> fmt::Debug for ForwardList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.iter().map(|item| item.value()))
+            .finish()
+    }
+}
+
+impl<T> Default for ForwardList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> ForwardList<T> {
+    /// Create a new empty list.
+    pub fn new() -> Self {
+        Self {
+            head: None,
+            tail: None,
+            items: SlotMap::with_key(),
+        }
+    }
+
+    /// Checks if the list contains the given index.
+    pub fn contains_key(&self, index: ForwardListIndex) -> bool {
+        self.items.contains_key(index)
+    }
+
+    /// Get the number of items in the list.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true if the list contains no items.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Get the first item in the list.
+    /// Can be None if the list is empty.
+    #[inline]
+    pub fn head(&self) -> Option<&ForwardListItem<T>> {
+        self.head.and_then(|head| self.get(head))
+    }
+
+    /// Get the last item in the list.
+    /// Can be None if the list is empty.
+    #[inline]
+    pub fn tail(&self) -> Option<&ForwardListItem<T>> {
+        self.tail.and_then(|tail| self.get(tail))
+    }
+
+    /// Convenience method to return a slotmap::SecondaryMap of type V
+    pub fn new_data<V>(&self) -> SecondaryMap<ForwardListIndex, V> {
+        SecondaryMap::new()
+    }
+
+    /// Get an item in the list.
+    #[inline]
+    pub fn get(&self, index: ForwardListIndex) -> Option<&ForwardListItem<T>> {
+        self.items.get(index)
+    }
+
+    /// Get a mutable reference to an item in the list.
+    #[inline]
+    pub fn get_mut(&mut self, index: ForwardListIndex) -> Option<&mut ForwardListItem<T>> {
+        self.items.get_mut(index)
+    }
+
+    /// Get the item after the item with the given index if it exists.
+    #[inline]
+    pub fn next_of(&self, index: ForwardListIndex) -> Option<&ForwardListItem<T>> {
+        self.cursor_next(index).and_then(|next| self.get(next))
+    }
+
+    /// Returns the next index of the item with the given index.
+    pub fn cursor_next(&self, index: ForwardListIndex) -> Option<ForwardListIndex> {
+        self.items.get(index).and_then(|item| item.next_index)
+    }
+
+    /// Add an item to the back of the list and return its index.
+    pub fn push_back(&mut self, value: T) -> ForwardListIndex {
+        let index = self.items.insert(ForwardListItem {
+            value,
+            next_index: None,
+        });
+        match self.tail {
+            Some(tail) => self.items[tail].next_index = Some(index),
+            None => self.head = Some(index),
+        }
+        self.tail = Some(index);
+        index
+    }
+
+    /// Add an item to the front of the list and return its index.
+    pub fn push_front(&mut self, value: T) -> ForwardListIndex {
+        let index = self.items.insert(ForwardListItem {
+            value,
+            next_index: self.head,
+        });
+        if self.tail.is_none() {
+            self.tail = Some(index);
+        }
+        self.head = Some(index);
+        index
+    }
+
+    /// Remove the first item in the list and return it (if it exists)
+    pub fn pop_front(&mut self) -> Option<T> {
+        let head = self.items.remove(self.head?)?;
+        self.head = head.next_index;
+        if self.head.is_none() {
+            self.tail = None;
+        }
+        Some(head.value)
+    }
+
+    /// Insert an item after the given index and return the index of the new item.
+    ///
+    /// # Panics
+    /// Panics if the index does not exist.
+    pub fn insert_after(&mut self, index: ForwardListIndex, value: T) -> ForwardListIndex {
+        let next_index = self.items[index].next_index;
+        let new_index = self.items.insert(ForwardListItem { value, next_index });
+        self.items[index].next_index = Some(new_index);
+        if next_index.is_none() {
+            self.tail = Some(new_index);
+        }
+        new_index
+    }
+
+    /// Remove the item after the given index and return its value.
+    ///
+    /// Returns None if the index does not exist or is the tail.
+    pub fn remove_after(&mut self, index: ForwardListIndex) -> Option<T> {
+        let next = self.cursor_next(index)?;
+        let removed = self.items.remove(next)?;
+        self.items[index].next_index = removed.next_index;
+        if removed.next_index.is_none() {
+            self.tail = Some(index);
+        }
+        Some(removed.value)
+    }
+
+    /// Push many items to the back of the list.
+    ///
+    /// Returns the indexes of the new items
+    pub fn extend<I>(&mut self, values: I) -> Vec<ForwardListIndex>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        values
+            .into_iter()
+            .map(|value| self.push_back(value))
+            .collect()
+    }
+
+    /// Returns an iterator that iterates over the items of the list from the head.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = ForwardItemRef<'_, T>> {
+        self.head
+            .into_iter()
+            .flat_map(move |head| self.iter_next(head))
+    }
+
+    /// Returns an iterator that iterates over the items of the list starting at the given index.
+    #[inline]
+    pub fn iter_next(
+        &self,
+        start: ForwardListIndex,
+    ) -> impl Iterator<Item = ForwardItemRef<'_, T>> {
+        self.cursor_iter_next(start)
+            .map(move |index| ForwardItemRef {
+                index,
+                item: &self.items[index],
+            })
+    }
+
+    /// Returns an iterator that iterates over the indexes of the list starting at the given index.
+    pub fn cursor_iter_next(
+        &self,
+        start: ForwardListIndex,
+    ) -> impl Iterator<Item = ForwardListIndex> + '_ {
+        let first = Some(start).filter(|start| self.items.contains_key(*start));
+        std::iter::successors(first, move |index| self.cursor_next(*index))
+    }
+}
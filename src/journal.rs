@@ -0,0 +1,440 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::Deref;
+
+use crate::events::ListEventKind;
+use crate::linked_list::{LinkedList, LinkedListIndex};
+
+/// A single invertible edit recorded by a [`JournaledList`].
+///
+/// Reverting an operation applies it backwards to the list and returns the operation that
+/// would apply it forwards again, which is what moves operations between the undo and redo stacks.
+#[derive(Debug)]
+enum Op<T> {
+    /// An item was inserted in between `prev` and `next`.
+    Insert {
+        index: LinkedListIndex,
+        prev: Option<LinkedListIndex>,
+        next: Option<LinkedListIndex>,
+    },
+    /// An item holding `value` was removed from in between `prev` and `next`.
+    Remove {
+        index: LinkedListIndex,
+        prev: Option<LinkedListIndex>,
+        next: Option<LinkedListIndex>,
+        value: T,
+    },
+    /// An item was moved from in between the `from` pair to in between the `to` pair.
+    Move {
+        index: LinkedListIndex,
+        from: (Option<LinkedListIndex>, Option<LinkedListIndex>),
+        to: (Option<LinkedListIndex>, Option<LinkedListIndex>),
+    },
+    /// The value of an item was replaced, `value` holds the value that is not currently in the list.
+    Replace { index: LinkedListIndex, value: T },
+}
+
+impl<T> Op<T> {
+    /// Every index the operation refers to.
+    fn indexes(&self) -> impl Iterator<Item = LinkedListIndex> {
+        let (index, rest) = match self {
+            Op::Insert { index, prev, next }
+            | Op::Remove {
+                index, prev, next, ..
+            } => (*index, [*prev, *next, None, None]),
+            Op::Move { index, from, to } => (*index, [from.0, from.1, to.0, to.1]),
+            Op::Replace { index, .. } => (*index, [None; 4]),
+        };
+        std::iter::once(index).chain(rest.into_iter().flatten())
+    }
+}
+
+/// A [`LinkedList`] wrapper that records every edit so it can be undone and redone.
+///
+/// Edits are collected into a group until [`checkpoint`](Self::checkpoint) is called,
+/// and [`undo`](Self::undo) / [`redo`](Self::redo) always work on whole groups.
+/// Making a new edit after undoing discards everything that could have been redone.
+///
+/// Undoing a removal (or redoing an insertion) has to allocate a new slot, so the restored item
+/// gets a new [`LinkedListIndex`]. The journal remembers which index replaced which, so the
+/// original index keeps working with every method of this type, and [`resolve`](Self::resolve)
+/// translates it into the index that is currently used by the underlying list. An old index is
+/// forgotten once no edit in the history refers to it anymore, so keep the original index
+/// rather than a resolved one across undo and redo.
+///
+/// Read access goes through `Deref<Target = LinkedList<T>>`.
+///
+/// # Example
+/// ```
+/// use fast_list::JournaledList;
+///
+/// let mut list = JournaledList::new();
+/// let a = list.push_back(1);
+/// list.push_back(2);
+/// list.checkpoint();
+///
+/// assert_eq!(list.remove(a), Some(1));
+/// list.push_front(3);
+/// assert_eq!(list.iter().map(|item| item.value).collect::<Vec<_>>(), vec![3, 2]);
+///
+/// list.undo();
+/// assert_eq!(list.iter().map(|item| item.value).collect::<Vec<_>>(), vec![1, 2]);
+/// assert_eq!(list.get(list.resolve(a).unwrap()).unwrap().value, 1);
+///
+/// list.redo();
+/// assert_eq!(list.iter().map(|item| item.value).collect::<Vec<_>>(), vec![3, 2]);
+/// ```
+pub struct JournaledList<T> {
+    list: LinkedList<T>,
+    /// Groups of operations that can be undone, the last group is undone first.
+    undo: Vec<Vec<Op<T>>>,
+    /// Groups of operations that can be redone, the last group is redone first.
+    redo: Vec<Vec<Op<T>>>,
+    /// Operations recorded since the last checkpoint.
+    pending: Vec<Op<T>>,
+    /// Maps the index of an item that was removed and later restored to the index it was restored with.
+    aliases: HashMap<LinkedListIndex, LinkedListIndex>,
+    /// Prune the aliases when there are more than this many.
+    prune_at: usize,
+}
+
+/// The fewest aliases that trigger pruning, so small journals don't rescan their history all the time.
+const MIN_PRUNE_AT: usize = 64;
+
+impl<T> Default for JournaledList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<LinkedList<T>> for JournaledList<T> {
+    /// Start journaling an existing list. The current state of the list is the oldest state that can be restored.
+    fn from(list: LinkedList<T>) -> Self {
+        Self {
+            list,
+            undo: Vec::new(),
+            redo: Vec::new(),
+            pending: Vec::new(),
+            aliases: HashMap::new(),
+            prune_at: MIN_PRUNE_AT,
+        }
+    }
+}
+
+impl<T> Deref for JournaledList<T> {
+    type Target = LinkedList<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl<T> JournaledList<T> {
+    /// Create a new empty journaled list.
+    pub fn new() -> Self {
+        Self::from(LinkedList::new())
+    }
+
+    /// Stop journaling and return the underlying list.
+    pub fn into_inner(self) -> LinkedList<T> {
+        self.list
+    }
+
+    /// Translate an index (possibly of an item that has since been removed and restored)
+    /// into the index the item currently has in the underlying list.
+    ///
+    /// Returns None if the item is not in the list.
+    pub fn resolve(&self, index: LinkedListIndex) -> Option<LinkedListIndex> {
+        let index = self.follow(index);
+        self.list.contains_key(index).then_some(index)
+    }
+
+    /// Close the current group of edits, so that the next [`undo`](Self::undo) stops here.
+    pub fn checkpoint(&mut self) {
+        if !self.pending.is_empty() {
+            self.undo.push(std::mem::take(&mut self.pending));
+        }
+    }
+
+    /// Returns true if there is anything to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.pending.is_empty() || !self.undo.is_empty()
+    }
+
+    /// Returns true if there is anything to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Forget all undo and redo history.
+    ///
+    /// This also forgets which old indexes map to restored items, so after this only the current
+    /// indexes of the items [`resolve`](Self::resolve).
+    pub fn clear_history(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.pending.clear();
+        self.aliases.clear();
+        self.prune_at = MIN_PRUNE_AT;
+    }
+
+    /// Undo the last group of edits, closing the current group first.
+    ///
+    /// Returns false if there was nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        self.checkpoint();
+        match self.undo.pop() {
+            Some(group) => {
+                let group = self.revert_group(group);
+                self.redo.push(group);
+                self.maybe_prune_aliases();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Redo the last undone group of edits.
+    ///
+    /// Returns false if there was nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        match self.redo.pop() {
+            Some(group) => {
+                let group = self.revert_group(group);
+                self.undo.push(group);
+                self.maybe_prune_aliases();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Add an item to the back of the list and return its index.
+    pub fn push_back(&mut self, value: T) -> LinkedListIndex {
+        let index = self.list.push_back(value);
+        self.record_insert(index);
+        index
+    }
+
+    /// Push an item to the front of the list and return its index.
+    pub fn push_front(&mut self, value: T) -> LinkedListIndex {
+        let index = self.list.push_front(value);
+        self.record_insert(index);
+        index
+    }
+
+    /// Insert an item after the given index and return the index of the new item.
+    pub fn insert_after(&mut self, index: LinkedListIndex, value: T) -> LinkedListIndex {
+        let index = self.list.insert_after(self.follow(index), value);
+        self.record_insert(index);
+        index
+    }
+
+    /// Insert an item before the given index and return the index of the new item.
+    pub fn insert_before(&mut self, index: LinkedListIndex, value: T) -> LinkedListIndex {
+        let index = self.list.insert_before(self.follow(index), value);
+        self.record_insert(index);
+        index
+    }
+
+    /// Move an item so that it comes directly after `after`, see [`LinkedList::move_after`].
+    pub fn move_after(&mut self, index: LinkedListIndex, after: LinkedListIndex) -> bool {
+        let (index, after) = (self.follow(index), self.follow(after));
+        let from = self.neighbours(index);
+        let moved = self.list.move_after(index, after);
+        if moved {
+            self.record_move(index, from);
+        }
+        moved
+    }
+
+    /// Move an item so that it comes directly before `before`, see [`LinkedList::move_before`].
+    pub fn move_before(&mut self, index: LinkedListIndex, before: LinkedListIndex) -> bool {
+        let (index, before) = (self.follow(index), self.follow(before));
+        let from = self.neighbours(index);
+        let moved = self.list.move_before(index, before);
+        if moved {
+            self.record_move(index, from);
+        }
+        moved
+    }
+
+    fn follow(&self, mut index: LinkedListIndex) -> LinkedListIndex {
+        while let Some(alias) = self.aliases.get(&index) {
+            index = *alias;
+        }
+        index
+    }
+
+    fn neighbours(
+        &self,
+        index: LinkedListIndex,
+    ) -> (Option<LinkedListIndex>, Option<LinkedListIndex>) {
+        self.list
+            .get(index)
+            .map_or((None, None), |item| (item.prev_index, item.next_index))
+    }
+
+    fn record(&mut self, op: Op<T>) {
+        self.pending.push(op);
+        if !self.redo.is_empty() {
+            self.redo.clear();
+            self.prune_aliases();
+        }
+    }
+
+    /// Undo and redo cycles leave a chain of intermediate indexes behind, prune them once there are many.
+    fn maybe_prune_aliases(&mut self) {
+        if self.aliases.len() > self.prune_at {
+            self.prune_aliases();
+        }
+    }
+
+    /// Forget the aliases of indexes that no operation in the history refers to,
+    /// and point the remaining ones straight at the current index.
+    fn prune_aliases(&mut self) {
+        if self.aliases.is_empty() {
+            return;
+        }
+        let history = self.undo.iter().chain(&self.redo).flatten();
+        let referenced = history
+            .chain(&self.pending)
+            .flat_map(Op::indexes)
+            .collect::<HashSet<_>>();
+        let aliases = referenced
+            .iter()
+            .filter(|index| self.aliases.contains_key(*index))
+            .map(|index| (*index, self.follow(*index)))
+            .collect::<HashMap<_, _>>();
+        self.aliases = aliases;
+        self.prune_at = (2 * self.aliases.len())
+            .max(referenced.len())
+            .max(MIN_PRUNE_AT);
+    }
+
+    fn record_insert(&mut self, index: LinkedListIndex) {
+        let (prev, next) = self.neighbours(index);
+        self.record(Op::Insert { index, prev, next });
+    }
+
+    fn record_move(
+        &mut self,
+        index: LinkedListIndex,
+        from: (Option<LinkedListIndex>, Option<LinkedListIndex>),
+    ) {
+        let to = self.neighbours(index);
+        self.record(Op::Move { index, from, to });
+    }
+
+    /// Revert a group of operations (last operation first) and return the group that reverts it back.
+    fn revert_group(&mut self, group: Vec<Op<T>>) -> Vec<Op<T>> {
+        group.into_iter().rev().map(|op| self.revert(op)).collect()
+    }
+
+    fn revert(&mut self, op: Op<T>) -> Op<T> {
+        match op {
+            Op::Insert { index, prev, next } => {
+                let item = self
+                    .list
+                    .remove(self.follow(index))
+                    .expect("journaled item is missing from the list");
+                Op::Remove {
+                    index,
+                    prev,
+                    next,
+                    value: item.value,
+                }
+            }
+            Op::Remove {
+                index,
+                prev,
+                next,
+                value,
+            } => {
+                let restored = match (prev.map(|i| self.follow(i)), next.map(|i| self.follow(i))) {
+                    (Some(prev), _) => self.list.insert_after(prev, value),
+                    (None, Some(next)) => self.list.insert_before(next, value),
+                    (None, None) => self.list.push_back(value),
+                };
+                let stale = self.follow(index);
+                self.aliases.insert(stale, restored);
+                Op::Insert { index, prev, next }
+            }
+            Op::Move { index, from, to } => {
+                let current = self.follow(index);
+                self.list.unlink(current);
+                let (prev, next) = (
+                    from.0.map(|i| self.follow(i)),
+                    from.1.map(|i| self.follow(i)),
+                );
+                self.list.link(current, prev, next);
+                self.list.emit(ListEventKind::Move, current, prev, next);
+                Op::Move {
+                    index,
+                    from: to,
+                    to: from,
+                }
+            }
+            Op::Replace { index, value } => {
+                let value = self
+                    .list
+                    .replace(self.follow(index), value)
+                    .expect("journaled item is missing from the list");
+                Op::Replace { index, value }
+            }
+        }
+    }
+}
+
+impl<T: Clone> JournaledList<T> {
+    /// Remove an item from the list, returning its value if it exists.
+    ///
+    /// The journal keeps a copy of the value so the removal can be undone.
+    pub fn remove(&mut self, index: LinkedListIndex) -> Option<T> {
+        let index = self.follow(index);
+        let item = self.list.remove(index)?;
+        self.record(Op::Remove {
+            index,
+            prev: item.prev_index,
+            next: item.next_index,
+            value: item.value.clone(),
+        });
+        Some(item.value)
+    }
+
+    /// Remove the last item in the list and return it (if it exists)
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.list.tail.and_then(|tail| self.remove(tail))
+    }
+
+    /// Remove the first item in the list and return it (if it exists)
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.list.head.and_then(|head| self.remove(head))
+    }
+
+    /// Replace the value of an item, returning the old value if the index exists.
+    pub fn replace(&mut self, index: LinkedListIndex, value: T) -> Option<T> {
+        let index = self.follow(index);
+        let old = self.list.replace(index, value)?;
+        self.record(Op::Replace {
+            index,
+            value: old.clone(),
+        });
+        Some(old)
+    }
+
+    /// Change the value of an item in place. Returns false if the index does not exist.
+    pub fn update<F>(&mut self, index: LinkedListIndex, f: F) -> bool
+    where
+        F: FnOnce(&mut T),
+    {
+        let index = self.follow(index);
+        let Some(item) = self.list.get_mut(index) else {
+            return false;
+        };
+        let old = item.value.clone();
+        f(&mut item.value);
+        self.list.mark_updated(index);
+        self.record(Op::Replace { index, value: old });
+        true
+    }
+}
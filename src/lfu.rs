@@ -0,0 +1,298 @@
+use core::borrow::Borrow;
+use core::hash::Hash;
+
+use hashbrown::HashMap;
+
+use crate::cache::{CachePolicy, CacheStats, EvictionCallback};
+use crate::linked_list::{LinkedList, LinkedListIndex};
+
+/// All keys that have been used the same number of times, most recently used at the front.
+struct Bucket<K> {
+    frequency: u64,
+    keys: LinkedList<K>,
+}
+
+struct LfuEntry<V> {
+    value: V,
+    /// The index of the bucket in `LfuCache::buckets`.
+    bucket: LinkedListIndex,
+    /// The index of the key in the `keys` list of the bucket.
+    node: LinkedListIndex,
+}
+
+/// A least frequently used cache, ties are broken by evicting the least recently used entry.
+///
+/// Uses the O(1) algorithm by Shah, Mitra and Matani: a [`LinkedList`] of frequency buckets in
+/// increasing order, where every bucket holds a [`LinkedList`] of the keys with that frequency.
+/// Using a key moves it to the next bucket, and the victim is always at the back of the first bucket.
+///
+/// # Example
+/// ```
+/// use fast_list::LfuCache;
+///
+/// let mut cache = LfuCache::new(2);
+/// cache.put("a", 1);
+/// cache.put("b", 2);
+/// cache.get(&"a");
+/// cache.get(&"a");
+/// cache.get(&"b");
+///
+/// // "b" was used less often than "a", so it is evicted.
+/// cache.put("c", 3);
+/// assert!(cache.peek(&"b").is_none());
+/// assert_eq!(cache.frequency(&"a"), Some(3));
+/// ```
+pub struct LfuCache<K, V> {
+    map: HashMap<K, LfuEntry<V>>,
+    buckets: LinkedList<Bucket<K>>,
+    cap: usize,
+    on_evict: Option<EvictionCallback<K, V>>,
+    stats: CacheStats,
+}
+
+impl<K: Hash + Eq + Clone, V> LfuCache<K, V> {
+    /// Create a new empty cache that holds at most `cap` entries.
+    pub fn new(cap: usize) -> Self {
+        Self {
+            map: HashMap::with_capacity(cap),
+            buckets: LinkedList::new(),
+            cap,
+            on_evict: None,
+            stats: CacheStats::default(),
+        }
+    }
+
+    /// Call `f` with every entry that is evicted because the cache is full.
+    pub fn with_eviction_callback<F>(mut self, f: F) -> Self
+    where
+        F: FnMut(K, V) + Send + 'static,
+    {
+        self.on_evict = Some(Box::new(f));
+        self
+    }
+
+    /// The maximum number of entries.
+    pub fn cap(&self) -> usize {
+        self.cap
+    }
+
+    /// Get the number of entries.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns true if the cache has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// The hit and miss counters of [`get`](Self::get) and [`get_mut`](Self::get_mut).
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    /// How many times a key has been used (inserted or looked up) since it was inserted.
+    pub fn frequency<Q>(&self, key: &Q) -> Option<u64>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let entry = self.map.get(key)?;
+        self.buckets.get(entry.bucket).map(|b| b.value.frequency)
+    }
+
+    /// Returns true if the cache contains the key, without counting it as a use.
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(key)
+    }
+
+    /// Get the value of a key and count it as a use.
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_mut(key).map(|value| &*value)
+    }
+
+    /// Get a mutable reference to the value of a key and count it as a use.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let hit = self.map.contains_key(key);
+        self.stats.record(hit);
+        if !hit {
+            return None;
+        }
+        self.touch(key);
+        self.map.get_mut(key).map(|entry| &mut entry.value)
+    }
+
+    /// Get the value of a key without counting it as a use.
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key).map(|entry| &entry.value)
+    }
+
+    /// Insert or update an entry. Updating counts as a use, new entries start with a frequency of 1.
+    ///
+    /// Returns the old value if the key was already in the cache.
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        if self.map.contains_key(&key) {
+            self.touch(&key);
+            let entry = self.map.get_mut(&key).unwrap();
+            return Some(std::mem::replace(&mut entry.value, value));
+        }
+        if self.cap == 0 {
+            self.evict(key, value);
+            return None;
+        }
+        if self.len() >= self.cap {
+            if let Some((key, value)) = self.pop_lfu() {
+                self.evict(key, value);
+            }
+        }
+
+        let bucket = match self.buckets.head() {
+            Some(head) if head.value.frequency == 1 => self.buckets.head.unwrap(),
+            _ => self.buckets.push_front(Bucket {
+                frequency: 1,
+                keys: LinkedList::new(),
+            }),
+        };
+        let node = self
+            .buckets
+            .get_mut(bucket)
+            .unwrap()
+            .value
+            .keys
+            .push_front(key.clone());
+        self.map.insert(
+            key,
+            LfuEntry {
+                value,
+                bucket,
+                node,
+            },
+        );
+        None
+    }
+
+    /// Remove an entry, returning its value if the key was in the cache.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let entry = self.map.remove(key)?;
+        self.unlink_key(entry.bucket, entry.node);
+        Some(entry.value)
+    }
+
+    /// Remove and return the entry that would be evicted next.
+    pub fn pop_lfu(&mut self) -> Option<(K, V)> {
+        let bucket = self.buckets.head?;
+        let key = self
+            .buckets
+            .get_mut(bucket)
+            .unwrap()
+            .value
+            .keys
+            .pop_back()?;
+        if self.buckets.get(bucket).unwrap().value.keys.is_empty() {
+            self.buckets.remove(bucket);
+        }
+        let entry = self.map.remove(&key).unwrap();
+        Some((key, entry.value))
+    }
+
+    /// Move a key from its bucket to the bucket with the next frequency.
+    fn touch<Q>(&mut self, key: &Q)
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let entry = &self.map[key];
+        let (bucket, node) = (entry.bucket, entry.node);
+        let frequency = self.buckets.get(bucket).unwrap().value.frequency + 1;
+
+        let next = match self.buckets.cursor_next(bucket) {
+            Some(next) if self.buckets.get(next).unwrap().value.frequency == frequency => next,
+            _ => self.buckets.insert_after(
+                bucket,
+                Bucket {
+                    frequency,
+                    keys: LinkedList::new(),
+                },
+            ),
+        };
+        let moved = self.unlink_key(bucket, node);
+        let node = self
+            .buckets
+            .get_mut(next)
+            .unwrap()
+            .value
+            .keys
+            .push_front(moved);
+
+        let entry = self.map.get_mut(key).unwrap();
+        entry.bucket = next;
+        entry.node = node;
+    }
+
+    /// Remove a key from a bucket, removing the bucket too if it becomes empty.
+    fn unlink_key(&mut self, bucket: LinkedListIndex, node: LinkedListIndex) -> K {
+        let keys = &mut self.buckets.get_mut(bucket).unwrap().value.keys;
+        let key = keys.remove(node).unwrap().value;
+        let empty = keys.is_empty();
+        if empty {
+            self.buckets.remove(bucket);
+        }
+        key
+    }
+
+    fn evict(&mut self, key: K, value: V) {
+        if let Some(on_evict) = &mut self.on_evict {
+            on_evict(key, value);
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, V> CachePolicy<K, V> for LfuCache<K, V> {
+    fn get(&mut self, key: &K) -> Option<&V> {
+        LfuCache::get(self, key)
+    }
+
+    fn peek(&self, key: &K) -> Option<&V> {
+        LfuCache::peek(self, key)
+    }
+
+    fn put(&mut self, key: K, value: V) -> Option<V> {
+        LfuCache::put(self, key, value)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        LfuCache::remove(self, key)
+    }
+
+    fn len(&self) -> usize {
+        LfuCache::len(self)
+    }
+
+    fn cap(&self) -> usize {
+        LfuCache::cap(self)
+    }
+
+    fn stats(&self) -> CacheStats {
+        LfuCache::stats(self)
+    }
+}
@@ -1,10 +1,66 @@
 #![crate_name = "fast_list"]
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "hashbrown")]
+mod arc;
+mod bounded;
+#[cfg(feature = "hashbrown")]
+mod cache;
+mod circular;
+mod crdt;
+mod diff;
+mod entry;
+mod events;
+mod forward_list;
+mod journal;
+#[cfg(feature = "hashbrown")]
+mod lfu;
+#[cfg(feature = "hashbrown")]
+mod linked_hash_map;
 mod linked_list;
+#[cfg(feature = "hashbrown")]
+mod lru;
+mod multi_list;
+mod piece_table;
+mod queue;
+mod reversed;
+mod skip_list;
+mod sorted;
+mod timer_wheel;
+mod transaction;
+mod tree;
+mod unrolled;
+#[cfg_attr(not(feature = "unstable"), allow(dead_code))]
 mod walker;
 
+#[cfg(feature = "hashbrown")]
+pub use arc::*;
+pub use bounded::*;
+#[cfg(feature = "hashbrown")]
+pub use cache::*;
+pub use circular::*;
+pub use crdt::*;
+pub use diff::*;
+pub use entry::*;
+pub use events::*;
+pub use forward_list::*;
+pub use journal::*;
+#[cfg(feature = "hashbrown")]
+pub use lfu::*;
+#[cfg(feature = "hashbrown")]
+pub use linked_hash_map::*;
 pub use linked_list::*;
+#[cfg(feature = "hashbrown")]
+pub use lru::*;
+pub use multi_list::*;
+pub use piece_table::*;
+pub use queue::*;
+pub use reversed::*;
+pub use skip_list::*;
+pub use timer_wheel::*;
+pub use transaction::*;
+pub use tree::*;
+pub use unrolled::*;
 
 #[cfg(feature = "unstable")]
 pub use walker::*;
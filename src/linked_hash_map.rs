@@ -0,0 +1,547 @@
+use core::borrow::Borrow;
+use core::hash::Hash;
+
+use hashbrown::HashMap;
+
+use crate::linked_list::{LinkedList, LinkedListIndex};
+
+/// A hash map that remembers the order its entries were inserted in.
+///
+/// The entries live in a [`LinkedList`] and a [`hashbrown::HashMap`] maps every key to the
+/// [`LinkedListIndex`] of its entry, so lookups, removals and reordering are all O(1).
+/// The index of an entry stays the same for as long as the entry is in the map,
+/// which makes it usable as a stable entry id (see [`get_index`](Self::get_index)).
+///
+/// # Example
+/// ```
+/// use fast_list::LinkedHashMap;
+///
+/// let mut map = LinkedHashMap::new();
+/// map.insert("a", 1);
+/// map.insert("b", 2);
+/// map.insert("c", 3);
+/// map.move_to_back(&"a");
+/// *map.entry("b").or_insert(0) += 10;
+///
+/// assert_eq!(map.iter().collect::<Vec<_>>(), vec![(&"b", &12), (&"c", &3), (&"a", &1)]);
+/// assert_eq!(map.pop_front(), Some(("b", 12)));
+/// ```
+pub struct LinkedHashMap<K, V> {
+    map: HashMap<K, LinkedListIndex>,
+    list: LinkedList<(K, V)>,
+}
+
+impl<K: Hash + Eq + Clone, V> Default for LinkedHashMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Hash + Eq + Clone, V> LinkedHashMap<K, V> {
+    /// Create a new empty map.
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Create a new empty map with room for `capacity` keys in the hash map.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            map: HashMap::with_capacity(capacity),
+            list: LinkedList::new(),
+        }
+    }
+
+    /// Get the number of entries.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns true if the map has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns true if the map contains the key.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(key)
+    }
+
+    /// Get the value of a key.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = *self.map.get(key)?;
+        self.list.get(index).map(|item| &item.value.1)
+    }
+
+    /// Get a mutable reference to the value of a key.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = *self.map.get(key)?;
+        self.list.get_mut(index).map(|item| &mut item.value.1)
+    }
+
+    /// Get the stable index of the entry of a key.
+    pub fn get_index<Q>(&self, key: &Q) -> Option<LinkedListIndex>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key).copied()
+    }
+
+    /// Get an entry by its stable index, without hashing the key.
+    pub fn get_by_index(&self, index: LinkedListIndex) -> Option<(&K, &V)> {
+        self.list
+            .get(index)
+            .map(|item| (&item.value.0, &item.value.1))
+    }
+
+    /// Get a mutable reference to the value of an entry by its stable index.
+    pub fn get_by_index_mut(&mut self, index: LinkedListIndex) -> Option<&mut V> {
+        self.list.get_mut(index).map(|item| &mut item.value.1)
+    }
+
+    /// Insert a value. New keys are added to the back, existing keys keep their position.
+    ///
+    /// Returns the old value if the key was already in the map.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.entry(key) {
+            Entry::Occupied(mut entry) => Some(entry.insert(value)),
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+                None
+            }
+        }
+    }
+
+    /// Get the entry of a key for in-place manipulation.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        match self.map.get(&key) {
+            Some(&index) => Entry::Occupied(OccupiedEntry { map: self, index }),
+            None => Entry::Vacant(VacantEntry { map: self, key }),
+        }
+    }
+
+    /// Remove an entry, returning its value if the key was in the map.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Remove an entry, returning the key and value if the key was in the map.
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.map.remove(key)?;
+        self.list.remove(index).map(|item| item.value)
+    }
+
+    /// Move an entry to the back of the iteration order. Returns false if the key is not in the map.
+    pub fn move_to_back<Q>(&mut self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match self.map.get(key) {
+            Some(&index) => self.list.move_to_back(index),
+            None => false,
+        }
+    }
+
+    /// Move an entry to the front of the iteration order. Returns false if the key is not in the map.
+    pub fn move_to_front<Q>(&mut self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match self.map.get(key) {
+            Some(&index) => self.list.move_to_front(index),
+            None => false,
+        }
+    }
+
+    /// Get the first entry.
+    pub fn front(&self) -> Option<(&K, &V)> {
+        self.list.head().map(|item| (&item.value.0, &item.value.1))
+    }
+
+    /// Get the last entry.
+    pub fn back(&self) -> Option<(&K, &V)> {
+        self.list.tail().map(|item| (&item.value.0, &item.value.1))
+    }
+
+    /// Remove and return the first entry.
+    pub fn pop_front(&mut self) -> Option<(K, V)> {
+        let (key, value) = self.list.pop_front()?;
+        self.map.remove(&key);
+        Some((key, value))
+    }
+
+    /// Remove and return the last entry.
+    pub fn pop_back(&mut self) -> Option<(K, V)> {
+        let (key, value) = self.list.pop_back()?;
+        self.map.remove(&key);
+        Some((key, value))
+    }
+
+    /// Remove all entries.
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.list.clear();
+    }
+
+    /// Returns an iterator over the entries in order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (&K, &V)> {
+        Iter {
+            list: &self.list,
+            front: self.list.head,
+            back: self.list.tail,
+        }
+    }
+
+    /// Returns an iterator over the keys in order.
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = &K> {
+        self.iter().map(|(key, _)| key)
+    }
+
+    /// Returns an iterator over the values in order.
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = &V> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    /// Returns an iterator over the stable indexes of the entries in order.
+    pub fn indexes(&self) -> impl Iterator<Item = LinkedListIndex> + '_ {
+        self.list
+            .head
+            .into_iter()
+            .flat_map(move |head| self.list.cursor_iter_next(head))
+    }
+}
+
+impl<K: Hash + Eq + Clone, V> FromIterator<(K, V)> for LinkedHashMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Hash + Eq + Clone, V> Extend<(K, V)> for LinkedHashMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+/// Iterates from both ends of the list until the ends meet.
+struct Iter<'a, K, V> {
+    list: &'a LinkedList<(K, V)>,
+    front: Option<LinkedListIndex>,
+    back: Option<LinkedListIndex>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.front?;
+        if self.front == self.back {
+            self.front = None;
+            self.back = None;
+        } else {
+            self.front = self.list.cursor_next(index);
+        }
+        self.list
+            .get(index)
+            .map(|item| (&item.value.0, &item.value.1))
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Iter<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.back?;
+        if self.front == self.back {
+            self.front = None;
+            self.back = None;
+        } else {
+            self.back = self.list.get(index).and_then(|item| item.prev_index);
+        }
+        self.list
+            .get(index)
+            .map(|item| (&item.value.0, &item.value.1))
+    }
+}
+
+/// A view into a single entry of a [`LinkedHashMap`], see [`LinkedHashMap::entry`].
+pub enum Entry<'a, K, V> {
+    /// The key is in the map.
+    Occupied(OccupiedEntry<'a, K, V>),
+    /// The key is not in the map.
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+/// An entry of a key that is in a [`LinkedHashMap`].
+pub struct OccupiedEntry<'a, K, V> {
+    map: &'a mut LinkedHashMap<K, V>,
+    index: LinkedListIndex,
+}
+
+/// An entry of a key that is not in a [`LinkedHashMap`].
+pub struct VacantEntry<'a, K, V> {
+    map: &'a mut LinkedHashMap<K, V>,
+    key: K,
+}
+
+impl<'a, K: Hash + Eq + Clone, V> Entry<'a, K, V> {
+    /// Insert `default` at the back if the key is not in the map, and return a reference to the value.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Insert the result of `default` at the back if the key is not in the map, and return a reference to the value.
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Call `f` with the value if the key is in the map.
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+
+    /// The key of the entry.
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => &entry.key,
+        }
+    }
+}
+
+impl<'a, K: Hash + Eq + Clone, V> OccupiedEntry<'a, K, V> {
+    /// The stable index of the entry.
+    pub fn index(&self) -> LinkedListIndex {
+        self.index
+    }
+
+    /// The key of the entry.
+    pub fn key(&self) -> &K {
+        &self.map.list.get(self.index).unwrap().value.0
+    }
+
+    /// Get the value of the entry.
+    pub fn get(&self) -> &V {
+        &self.map.list.get(self.index).unwrap().value.1
+    }
+
+    /// Get a mutable reference to the value of the entry.
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.map.list.get_mut(self.index).unwrap().value.1
+    }
+
+    /// Turn the entry into a mutable reference to its value.
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.map.list.get_mut(self.index).unwrap().value.1
+    }
+
+    /// Replace the value of the entry and return the old value. The entry keeps its position.
+    pub fn insert(&mut self, value: V) -> V {
+        std::mem::replace(self.get_mut(), value)
+    }
+
+    /// Move the entry to the back of the iteration order.
+    pub fn move_to_back(&mut self) {
+        self.map.list.move_to_back(self.index);
+    }
+
+    /// Remove the entry and return its key and value.
+    pub fn remove_entry(self) -> (K, V) {
+        let (key, value) = self.map.list.remove(self.index).unwrap().value;
+        self.map.map.remove(&key);
+        (key, value)
+    }
+
+    /// Remove the entry and return its value.
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+}
+
+impl<'a, K: Hash + Eq + Clone, V> VacantEntry<'a, K, V> {
+    /// The key of the entry.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Insert the value at the back and return a reference to it.
+    pub fn insert(self, value: V) -> &'a mut V {
+        let index = self.map.list.push_back((self.key.clone(), value));
+        self.map.map.insert(self.key, index);
+        &mut self.map.list.get_mut(index).unwrap().value.1
+    }
+}
+
+/// A hash set that remembers the order its keys were inserted in, see [`LinkedHashMap`].
+///
+/// # Example
+/// ```
+/// use fast_list::LinkedHashSet;
+///
+/// let mut set: LinkedHashSet<_> = [3, 1, 2].into_iter().collect();
+/// assert!(!set.insert(1));
+/// set.move_to_back(&3);
+/// assert_eq!(set.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+/// ```
+pub struct LinkedHashSet<K> {
+    map: LinkedHashMap<K, ()>,
+}
+
+impl<K: Hash + Eq + Clone> Default for LinkedHashSet<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Hash + Eq + Clone> LinkedHashSet<K> {
+    /// Create a new empty set.
+    pub fn new() -> Self {
+        Self {
+            map: LinkedHashMap::new(),
+        }
+    }
+
+    /// Get the number of keys.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns true if the set has no keys.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns true if the set contains the key.
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(key)
+    }
+
+    /// Get the stable index of a key.
+    pub fn get_index<Q>(&self, key: &Q) -> Option<LinkedListIndex>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get_index(key)
+    }
+
+    /// Get a key by its stable index.
+    pub fn get_by_index(&self, index: LinkedListIndex) -> Option<&K> {
+        self.map.get_by_index(index).map(|(key, _)| key)
+    }
+
+    /// Add a key to the back. Returns false (and keeps the position) if the key was already in the set.
+    pub fn insert(&mut self, key: K) -> bool {
+        self.map.insert(key, ()).is_none()
+    }
+
+    /// Remove a key. Returns false if the key was not in the set.
+    pub fn remove<Q>(&mut self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.remove(key).is_some()
+    }
+
+    /// Move a key to the back of the iteration order. Returns false if the key is not in the set.
+    pub fn move_to_back<Q>(&mut self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.move_to_back(key)
+    }
+
+    /// Move a key to the front of the iteration order. Returns false if the key is not in the set.
+    pub fn move_to_front<Q>(&mut self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.move_to_front(key)
+    }
+
+    /// Get the first key.
+    pub fn front(&self) -> Option<&K> {
+        self.map.front().map(|(key, _)| key)
+    }
+
+    /// Get the last key.
+    pub fn back(&self) -> Option<&K> {
+        self.map.back().map(|(key, _)| key)
+    }
+
+    /// Remove and return the first key.
+    pub fn pop_front(&mut self) -> Option<K> {
+        self.map.pop_front().map(|(key, _)| key)
+    }
+
+    /// Remove and return the last key.
+    pub fn pop_back(&mut self) -> Option<K> {
+        self.map.pop_back().map(|(key, _)| key)
+    }
+
+    /// Remove all keys.
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// Returns an iterator over the keys in order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &K> {
+        self.map.keys()
+    }
+}
+
+impl<K: Hash + Eq + Clone> FromIterator<K> for LinkedHashSet<K> {
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<K: Hash + Eq + Clone> Extend<K> for LinkedHashSet<K> {
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        for key in iter {
+            self.insert(key);
+        }
+    }
+}
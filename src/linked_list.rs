@@ -1,6 +1,9 @@
 use core::fmt;
+use core::ops::Deref;
 use slotmap::{new_key_type, SecondaryMap, SlotMap, SparseSecondaryMap};
 
+use crate::events::{ListEvent, ListEventKind};
+
 #[cfg(feature = "unstable")]
 use crate::{LinkedListWalker, Walker};
 
@@ -11,8 +14,6 @@ new_key_type! {
 
 #[derive(Debug)]
 pub struct LinkedListItem<T> {
-    /// The index of the item in the list.
-    pub index: LinkedListIndex,
     /// The value of the item.
     pub value: T,
     /// The index of the next item in the list.
@@ -21,6 +22,51 @@ pub struct LinkedListItem<T> {
     pub prev_index: Option<LinkedListIndex>,
 }
 
+/// A view of an item together with its index, as yielded by the iterators of [`LinkedList`].
+///
+/// Items don't store their own index, so the iterators pair it up with the item instead.
+/// Derefs to the [`LinkedListItem`], so `item.value` and `item.next_index` work as usual.
+#[derive(Debug)]
+pub struct ItemRef<'a, T> {
+    /// The index of the item in the list.
+    pub index: LinkedListIndex,
+    item: &'a LinkedListItem<T>,
+}
+
+impl<'a, T> Clone for ItemRef<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for ItemRef<'a, T> {}
+
+impl<'a, T> Deref for ItemRef<'a, T> {
+    type Target = LinkedListItem<T>;
+
+    fn deref(&self) -> &Self::Target {
+        self.item
+    }
+}
+
+impl<'a, T> ItemRef<'a, T> {
+    /// The item, borrowed for as long as the list.
+    pub fn item(&self) -> &'a LinkedListItem<T> {
+        self.item
+    }
+
+    /// The value of the item, borrowed for as long as the list.
+    pub fn value(&self) -> &'a T {
+        &self.item.value
+    }
+}
+
+impl<'a, T> From<ItemRef<'a, T>> for (LinkedListIndex, &'a T) {
+    fn from(item: ItemRef<'a, T>) -> Self {
+        (item.index, &item.item.value)
+    }
+}
+
 /// A doubly linked list using SlotMap for better cache performance than a linked list using pointers, and which also solves the ABA problem.
 pub struct LinkedList<T = ()> {
     /// The index of the first item in the list.
@@ -29,6 +75,8 @@ pub struct LinkedList<T = ()> {
     pub tail: Option<LinkedListIndex>,
     /// The items in the list.
     items: SlotMap<LinkedListIndex, LinkedListItem<T>>,
+    /// The change log, only recorded while events are enabled.
+    events: Option<Vec<ListEvent>>,
 }
 
 impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
@@ -37,6 +85,12 @@ impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
     }
 }
 
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> LinkedList<T> {
     /// Create a new empty list.
     pub fn new() -> Self {
@@ -44,6 +98,7 @@ impl<T> LinkedList<T> {
             head: None,
             tail: None,
             items: SlotMap::with_key(),
+            events: None,
         }
     }
 
@@ -109,14 +164,13 @@ impl<T> LinkedList<T> {
     /// Get an item in the list.
     #[inline]
     pub fn get(&self, index: LinkedListIndex) -> Option<&LinkedListItem<T>> {
-        self.items.get(index).map(|item| item)
+        self.items.get(index)
     }
 
     /// Get a mutable reference to an item in the list.
     #[inline]
     pub fn get_mut(&mut self, index: LinkedListIndex) -> Option<&mut LinkedListItem<T>> {
-        let item = self.items.get_mut(index);
-        item
+        self.items.get_mut(index)
     }
 
     /// Get the item after the item with the given index if it exists.
@@ -138,7 +192,7 @@ impl<T> LinkedList<T> {
     /// Get a mutable reference to the item after the item with the given index if it exists.
     pub fn next_of_mut(&mut self, index: LinkedListIndex) -> Option<&mut LinkedListItem<T>> {
         let item = self.items.get_mut(index);
-        let next = item.and_then(|item| item.prev_index);
+        let next = item.and_then(|item| item.next_index);
         if let Some(next) = next {
             self.items.get_mut(next)
         } else {
@@ -161,10 +215,9 @@ impl<T> LinkedList<T> {
     pub fn insert_after(&mut self, index: LinkedListIndex, value: T) -> LinkedListIndex {
         let next_index = self.items.get(index).unwrap().next_index;
 
-        let new_index = self.items.insert_with_key(|i| LinkedListItem {
-            index: i,
+        let new_index = self.items.insert(LinkedListItem {
             value,
-            next_index: next_index,
+            next_index,
             prev_index: Some(index),
         });
 
@@ -183,6 +236,8 @@ impl<T> LinkedList<T> {
             item.next_index = Some(new_index);
         }
 
+        self.emit(ListEventKind::Insert, new_index, Some(index), next_index);
+
         // Return the new element
         new_index
     }
@@ -191,11 +246,10 @@ impl<T> LinkedList<T> {
     pub fn insert_before(&mut self, index: LinkedListIndex, value: T) -> LinkedListIndex {
         let prev_index = self.items.get(index).unwrap().prev_index;
 
-        let new_index = self.items.insert_with_key(|i| LinkedListItem {
-            index: i,
+        let new_index = self.items.insert(LinkedListItem {
             value,
             next_index: Some(index),
-            prev_index: prev_index,
+            prev_index,
         });
 
         let items = &mut self.items;
@@ -212,13 +266,15 @@ impl<T> LinkedList<T> {
         // Update the element we insert before to point its `prev` to the new element.
         item.prev_index = Some(new_index);
 
+        self.emit(ListEventKind::Insert, new_index, prev_index, Some(index));
+
         new_index
     }
 
     /// Add an item to the back of the list and return its index.
     pub fn push_back(&mut self, value: T) -> LinkedListIndex {
-        let index = self.items.insert_with_key(|i| LinkedListItem {
-            index: i,
+        let old_tail = self.tail;
+        let index = self.items.insert(LinkedListItem {
             value,
             next_index: None,
             prev_index: self.tail,
@@ -237,13 +293,15 @@ impl<T> LinkedList<T> {
 
         self.tail = Some(index);
 
+        self.emit(ListEventKind::Insert, index, old_tail, None);
+
         index
     }
 
     /// Push an item to the front of the list.
     pub fn push_front(&mut self, value: T) -> LinkedListIndex {
-        let index = self.items.insert_with_key(|i| LinkedListItem {
-            index: i,
+        let old_head = self.head;
+        let index = self.items.insert(LinkedListItem {
             value,
             next_index: self.head,
             prev_index: None,
@@ -261,13 +319,15 @@ impl<T> LinkedList<T> {
 
         self.head = Some(index);
 
+        self.emit(ListEventKind::Insert, index, None, old_head);
+
         index
     }
 
     /// Remove the last item in the list and return it (if it exists)
     pub fn pop_back(&mut self) -> Option<T> {
-        self.tail.and_then(|old_tail| {
-            let old_tail = self.items.remove(old_tail);
+        self.tail.and_then(|old_tail_index| {
+            let old_tail = self.items.remove(old_tail_index);
 
             if let Some(old_tail) = old_tail {
                 self.tail = old_tail.prev_index;
@@ -282,6 +342,13 @@ impl<T> LinkedList<T> {
                     }
                 }
 
+                self.emit(
+                    ListEventKind::Remove,
+                    old_tail_index,
+                    old_tail.prev_index,
+                    None,
+                );
+
                 Some(old_tail.value)
             } else {
                 None
@@ -291,8 +358,8 @@ impl<T> LinkedList<T> {
 
     /// Remove the first item in the list and return it (if it exists)
     pub fn pop_front(&mut self) -> Option<T> {
-        self.head.and_then(|old_head| {
-            let old_head = self.items.remove(old_head);
+        self.head.and_then(|old_head_index| {
+            let old_head = self.items.remove(old_head_index);
             if let Some(old_head) = old_head {
                 self.head = old_head.next_index;
                 match old_head.next_index {
@@ -303,6 +370,12 @@ impl<T> LinkedList<T> {
                         self.tail = None;
                     }
                 }
+                self.emit(
+                    ListEventKind::Remove,
+                    old_head_index,
+                    None,
+                    old_head.next_index,
+                );
                 Some(old_head.value)
             } else {
                 None
@@ -325,28 +398,42 @@ impl<T> LinkedList<T> {
     /// );
     /// ```
     #[inline]
-    pub fn iter(&self) -> impl Iterator<Item = &LinkedListItem<T>> {
+    pub fn iter(&self) -> impl Iterator<Item = ItemRef<'_, T>> {
         self.iter_next(self.head.unwrap())
     }
 
     /// Returns an iterator that iterates over the items of the list in no particular order.
     #[inline]
-    pub fn iter_unordered(&self) -> impl Iterator<Item = &LinkedListItem<T>> {
-        self.items.values()
+    pub fn iter_unordered(&self) -> impl Iterator<Item = ItemRef<'_, T>> {
+        self.items
+            .iter()
+            .map(|(index, item)| ItemRef { index, item })
     }
 
     /// Returns an iterator that iterates over the items of the list.
     #[inline]
-    pub fn iter_next(&self, start: LinkedListIndex) -> impl Iterator<Item = &LinkedListItem<T>> {
+    pub fn iter_next(&self, start: LinkedListIndex) -> impl Iterator<Item = ItemRef<'_, T>> {
         self.cursor_iter_next(start)
-            .map(move |index| self.items.get(index).unwrap())
+            .map(move |index| self.item_ref(index))
     }
 
     /// Returns an iterator that iterates over the items of the list in reverse order.
     #[inline]
-    pub fn iter_prev(&self, start: LinkedListIndex) -> impl Iterator<Item = &LinkedListItem<T>> {
+    pub fn iter_prev(&self, start: LinkedListIndex) -> impl Iterator<Item = ItemRef<'_, T>> {
         self.cursor_iter_prev(start)
-            .map(move |index| self.items.get(index).unwrap())
+            .map(move |index| self.item_ref(index))
+    }
+
+    /// Borrow an item together with its index.
+    ///
+    /// # Panics
+    /// Panics if the index does not exist.
+    #[inline]
+    pub(crate) fn item_ref(&self, index: LinkedListIndex) -> ItemRef<'_, T> {
+        ItemRef {
+            index,
+            item: &self.items[index],
+        }
     }
 
     /// Returns the next index of the item with the given index.
@@ -356,7 +443,7 @@ impl<T> LinkedList<T> {
 
     /// Returns the previous index of the item with the given index.
     pub fn cursor_prev(&self, item: LinkedListIndex) -> Option<LinkedListIndex> {
-        self.items.get(item).and_then(|item| item.next_index)
+        self.items.get(item).and_then(|item| item.prev_index)
     }
 
     /// Returns an iterator that iterates over the indexes of the list.
@@ -384,34 +471,20 @@ impl<T> LinkedList<T> {
     /// Splits the list into two at the given index. Returns a new list containing everything after the given index, including the index.
     /// This operation should compute in O(n) time.
     ///
-    /// T needs to implement Clone only to be able to insert the index, but not for the following items.
-    ///
     /// The old indexes will become invalid after this operation and new indexes can be
     /// retrieved by iterating from the head & tail of the new list.
+    /// If events are enabled, every moved item shows up as removed from this list.
     pub fn split_off(&mut self, index: LinkedListIndex) -> Self {
         let mut new_list = Self::new();
-        // let mut walker = LinkedListWalker::new(&self, index, false);
-
-        // let first = self.remove(index);
-        // if let Some(first) = first {
-        //     new_list.push_back(first.value);
-        // }
-        // while let Some(next) = walker.walk_next(&new_list) {
-        //     self.remove(next).map(|item| new_list.push_back(item.value));
-        // }
-        let mut current = index;
-
-        let first = self.remove(index);
-        if let Some(first) = first {
-            new_list.push_back(first.value);
-        }
-        while let Some(next) = self.cursor_next(current) {
-            let removed = self.remove(next);
-            if let Some(removed) = removed {
+        let mut current = Some(index);
+
+        while let Some(index) = current {
+            current = self.cursor_next(index);
+            if let Some(removed) = self.remove(index) {
                 new_list.push_back(removed.value);
             }
         }
-        return new_list;
+        new_list
     }
 
     /// Returns the nth index by iterating from the head or tail, whichever is closer.
@@ -460,12 +533,139 @@ impl<T> LinkedList<T> {
         indexes
     }
 
+    /// Insert many items after the given index, in order.
+    /// This operation should compute in O(k) time, where k is the number of new items.
+    ///
+    /// The new items are linked to each other first and then linked into the list in one go.
+    /// Returns the indexes of the new items.
+    ///
+    /// # Example
+    /// ```
+    /// use fast_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// let indexes = list.extend([1, 5]);
+    /// let new = list.splice_after(indexes[0], 2..5);
+    /// assert_eq!(new.len(), 3);
+    /// assert_eq!(list.iter().map(|item| item.value).collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the index does not exist.
+    pub fn splice_after<I>(&mut self, index: LinkedListIndex, values: I) -> Vec<LinkedListIndex>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let next = self.items.get(index).unwrap().next_index;
+        self.splice_between(Some(index), next, values)
+    }
+
+    /// Insert many items before the given index, in order, see [`splice_after`](Self::splice_after).
+    ///
+    /// # Panics
+    /// Panics if the index does not exist.
+    pub fn splice_before<I>(&mut self, index: LinkedListIndex, values: I) -> Vec<LinkedListIndex>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let prev = self.items.get(index).unwrap().prev_index;
+        self.splice_between(prev, Some(index), values)
+    }
+
+    /// Move all items of another list after the given index, see [`splice_after`](Self::splice_after).
+    ///
+    /// The moved items get new indexes; the returned map translates the old indexes into the new ones.
+    ///
+    /// # Panics
+    /// Panics if the index does not exist.
+    pub fn splice_list_after(
+        &mut self,
+        index: LinkedListIndex,
+        mut other: LinkedList<T>,
+    ) -> SecondaryMap<LinkedListIndex, LinkedListIndex> {
+        let mut old_indexes = Vec::with_capacity(other.len());
+        let mut values = Vec::with_capacity(other.len());
+        while let Some(head) = other.head {
+            old_indexes.push(head);
+            values.extend(other.pop_front());
+        }
+
+        let new_indexes = self.splice_after(index, values);
+        old_indexes.into_iter().zip(new_indexes).collect()
+    }
+
+    /// Link new items in between `prev` and `next`, which must be adjacent in the list.
+    fn splice_between<I>(
+        &mut self,
+        prev: Option<LinkedListIndex>,
+        next: Option<LinkedListIndex>,
+        values: I,
+    ) -> Vec<LinkedListIndex>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        // Collect first so a panicking iterator can't leave unlinked items behind.
+        let values = values.into_iter().collect::<Vec<_>>();
+        let mut indexes: Vec<LinkedListIndex> = Vec::with_capacity(values.len());
+        for value in values {
+            let last = indexes.last().copied();
+            let index = self.items.insert(LinkedListItem {
+                value,
+                next_index: next,
+                prev_index: last.or(prev),
+            });
+            if let Some(last) = last {
+                self.items[last].next_index = Some(index);
+            }
+            indexes.push(index);
+        }
+
+        let (Some(&first), Some(&last)) = (indexes.first(), indexes.last()) else {
+            return indexes;
+        };
+        match prev {
+            Some(prev) => self.items[prev].next_index = Some(first),
+            None => self.head = Some(first),
+        }
+        match next {
+            Some(next) => self.items[next].prev_index = Some(last),
+            None => self.tail = Some(last),
+        }
+
+        // Report the items as inserted one after the other, so the events can be replayed in order.
+        for &index in &indexes {
+            let prev = self.items[index].prev_index;
+            self.emit(ListEventKind::Insert, index, prev, next);
+        }
+        indexes
+    }
+
     /// Get the number of items in the list.
     #[inline]
     pub fn len(&self) -> usize {
         self.items.len()
     }
 
+    /// Returns true if the list contains no items.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Remove all items from the list.
+    ///
+    /// The storage is cleared in place, so old indexes never match items that are added later.
+    /// If events are enabled, every item shows up as removed from the front.
+    pub fn clear(&mut self) {
+        if self.events.is_some() {
+            while self.pop_front().is_some() {}
+        } else {
+            self.items.clear();
+            self.head = None;
+            self.tail = None;
+        }
+    }
+
     /// Remove an item from the list, returning the value at the key if the key was not previously removed.
     pub fn remove(&mut self, index: LinkedListIndex) -> Option<LinkedListItem<T>> {
         let item = self.items.remove(index)?;
@@ -486,39 +686,445 @@ impl<T> LinkedList<T> {
             self.tail = item.prev_index;
         }
 
+        self.emit(
+            ListEventKind::Remove,
+            index,
+            item.prev_index,
+            item.next_index,
+        );
+
         Some(item)
     }
 
-    /// Retain only the elements specified by the predicate.
+    /// Replace the value of an item, returning the old value if the index exists.
+    pub fn replace(&mut self, index: LinkedListIndex, value: T) -> Option<T> {
+        let item = self.items.get_mut(index)?;
+        let old = std::mem::replace(&mut item.value, value);
+        let (prev, next) = (item.prev_index, item.next_index);
+        self.emit(ListEventKind::Update, index, prev, next);
+        Some(old)
+    }
+
+    /// Move an item so that it comes directly after `after`, keeping its index.
+    ///
+    /// Returns false (and does nothing) if either index does not exist or if they are the same.
+    pub fn move_after(&mut self, index: LinkedListIndex, after: LinkedListIndex) -> bool {
+        if index == after || !self.contains_key(index) || !self.contains_key(after) {
+            return false;
+        }
+        self.unlink(index);
+        let next = self.items[after].next_index;
+        self.link(index, Some(after), next);
+        self.emit(ListEventKind::Move, index, Some(after), next);
+        true
+    }
+
+    /// Move an item so that it comes directly before `before`, keeping its index.
+    ///
+    /// Returns false (and does nothing) if either index does not exist or if they are the same.
+    pub fn move_before(&mut self, index: LinkedListIndex, before: LinkedListIndex) -> bool {
+        if index == before || !self.contains_key(index) || !self.contains_key(before) {
+            return false;
+        }
+        self.unlink(index);
+        let prev = self.items[before].prev_index;
+        self.link(index, prev, Some(before));
+        self.emit(ListEventKind::Move, index, prev, Some(before));
+        true
+    }
+
+    /// Move an item to the front of the list, keeping its index.
+    ///
+    /// Returns false if the index does not exist.
+    pub fn move_to_front(&mut self, index: LinkedListIndex) -> bool {
+        match self.head {
+            Some(head) if head != index => self.move_before(index, head),
+            _ => self.contains_key(index),
+        }
+    }
+
+    /// Move an item to the back of the list, keeping its index.
+    ///
+    /// Returns false if the index does not exist.
+    pub fn move_to_back(&mut self, index: LinkedListIndex) -> bool {
+        match self.tail {
+            Some(tail) if tail != index => self.move_after(index, tail),
+            _ => self.contains_key(index),
+        }
+    }
+
+    /// Reverse the order of the list in place, keeping all indexes.
+    /// This operation should compute in O(n) time.
+    ///
+    /// If events are enabled, every item but the old head shows up as moved to the front, in
+    /// the old order, so replaying the events reverses the list as well.
+    pub fn reverse(&mut self) {
+        let mut current = self.head;
+        while let Some(index) = current {
+            let item = &mut self.items[index];
+            current = item.next_index;
+            std::mem::swap(&mut item.next_index, &mut item.prev_index);
+            // Moving each item in front of its old previous item, which is the head by then.
+            if let Some(old_prev) = item.next_index {
+                self.emit(ListEventKind::Move, index, None, Some(old_prev));
+            }
+        }
+        std::mem::swap(&mut self.head, &mut self.tail);
+    }
+
+    /// Start recording a [`ListEvent`] for every change made to the list.
+    ///
+    /// Changes made through `get_mut` and friends can not be observed, use [`replace`](Self::replace)
+    /// or [`mark_updated`](Self::mark_updated) for changes that should show up in the log.
+    pub fn enable_events(&mut self) {
+        self.events.get_or_insert_with(Vec::new);
+    }
+
+    /// Stop recording events and discard the ones that were not drained yet.
+    pub fn disable_events(&mut self) {
+        self.events = None;
+    }
+
+    /// Returns true if events are being recorded.
+    pub fn events_enabled(&self) -> bool {
+        self.events.is_some()
+    }
+
+    /// Removes all recorded events from the log and returns them in the order they happened.
+    ///
+    /// # Example
+    /// ```
+    /// use fast_list::{LinkedList, ListEventKind};
+    ///
+    /// let mut list = LinkedList::new();
+    /// list.enable_events();
+    /// let a = list.push_back(1);
+    /// let b = list.push_back(2);
+    /// list.remove(a);
+    ///
+    /// let events = list.drain_events().collect::<Vec<_>>();
+    /// assert_eq!(events.len(), 3);
+    /// assert_eq!(events[1].kind, ListEventKind::Insert);
+    /// assert_eq!(events[1].prev, Some(a));
+    /// assert_eq!(events[2].kind, ListEventKind::Remove);
+    /// assert_eq!(events[2].next, Some(b));
+    /// assert_eq!(list.drain_events().count(), 0);
+    /// ```
+    pub fn drain_events(&mut self) -> impl Iterator<Item = ListEvent> + '_ {
+        self.events.iter_mut().flat_map(|events| events.drain(..))
+    }
+
+    /// Record an [`ListEventKind::Update`] event for a value that was changed in place.
+    ///
+    /// Returns false if the index does not exist.
+    pub fn mark_updated(&mut self, index: LinkedListIndex) -> bool {
+        let Some(item) = self.items.get(index) else {
+            return false;
+        };
+        let (prev, next) = (item.prev_index, item.next_index);
+        self.emit(ListEventKind::Update, index, prev, next);
+        true
+    }
+
+    /// Push an event to the log if events are enabled.
+    #[inline]
+    pub(crate) fn emit(
+        &mut self,
+        kind: ListEventKind,
+        index: LinkedListIndex,
+        prev: Option<LinkedListIndex>,
+        next: Option<LinkedListIndex>,
+    ) {
+        if let Some(events) = &mut self.events {
+            events.push(ListEvent {
+                kind,
+                index,
+                prev,
+                next,
+            });
+        }
+    }
+
+    /// Detach an item from its neighbours (and head/tail) without removing it from the storage.
+    ///
+    /// Returns the `(prev, next)` indexes the item had before it was detached.
+    pub(crate) fn unlink(
+        &mut self,
+        index: LinkedListIndex,
+    ) -> Option<(Option<LinkedListIndex>, Option<LinkedListIndex>)> {
+        let item = self.items.get_mut(index)?;
+        let (prev, next) = (item.prev_index.take(), item.next_index.take());
+
+        match prev {
+            Some(prev) => self.items[prev].next_index = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => self.items[next].prev_index = prev,
+            None => self.tail = prev,
+        }
+
+        Some((prev, next))
+    }
+
+    /// Remove an item that was detached with [`unlink`](Self::unlink) from the storage
+    /// without touching its (former) neighbours.
+    pub(crate) fn remove_detached(&mut self, index: LinkedListIndex) -> Option<T> {
+        self.items.remove(index).map(|item| item.value)
+    }
+
+    /// Link a detached item in between `prev` and `next`, which must be adjacent in the list.
+    /// A `None` on either side means the item becomes the new head or tail respectively.
+    pub(crate) fn link(
+        &mut self,
+        index: LinkedListIndex,
+        prev: Option<LinkedListIndex>,
+        next: Option<LinkedListIndex>,
+    ) {
+        let item = &mut self.items[index];
+        item.prev_index = prev;
+        item.next_index = next;
+
+        match prev {
+            Some(prev) => self.items[prev].next_index = Some(index),
+            None => self.head = Some(index),
+        }
+        match next {
+            Some(next) => self.items[next].prev_index = Some(index),
+            None => self.tail = Some(index),
+        }
+    }
+
+    /// Retain only the elements specified by the predicate, which can modify the values it keeps.
     /// This operation should compute in O(n) time.
     /// Modifies the list in place.
-    pub fn retain_mut<F>(&mut self, mut f: F)
+    ///
+    /// If the predicate panics, the items visited so far have been filtered and the rest are kept.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        self.extract_if(|_, value| !f(value)).for_each(drop);
+    }
+
+    /// Same as [`retain`](Self::retain).
+    pub fn retain_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        self.retain(f);
+    }
+
+    /// Retain only the items specified by the predicate, which gets the whole list and the index
+    /// of the item so it can look at its neighbours.
+    /// This operation should compute in O(n) time.
+    ///
+    /// All items are judged before any is removed, so the neighbours the predicate sees are
+    /// always the original ones, and the list is left untouched if the predicate panics.
+    ///
+    /// # Example
+    /// ```
+    /// use fast_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// list.extend([1, 5, 2, 8, 3]);
+    /// // Keep the local maxima.
+    /// list.retain_with_index(|list, index| {
+    ///     let value = list.get(index).unwrap().value;
+    ///     list.prev_of(index).map_or(true, |prev| prev.value < value)
+    ///         && list.next_of(index).map_or(true, |next| next.value < value)
+    /// });
+    /// assert_eq!(list.iter().map(|item| item.value).collect::<Vec<_>>(), vec![5, 8]);
+    /// ```
+    pub fn retain_with_index<F>(&mut self, mut f: F)
     where
-        F: FnMut(&T) -> bool,
+        F: FnMut(&Self, LinkedListIndex) -> bool,
     {
+        let mut removed = Vec::new();
         let mut current = self.head;
         while let Some(index) = current {
-            let item = self.items.get(index).unwrap();
-            let next = item.next_index;
-            if !f(&item.value) {
-                self.remove(index);
+            current = self.cursor_next(index);
+            if !f(self, index) {
+                removed.push(index);
             }
-            current = next;
+        }
+        for index in removed {
+            self.remove(index);
         }
     }
 
-    /// Returns a cloned list retaining only the elements specified by the predicate.
-    pub fn retain<F>(&self, mut f: F) -> Self
+    /// Remove the items for which the predicate returns true, and yield their indexes and values.
+    /// This operation should compute in O(n) time.
+    ///
+    /// The predicate can modify the values it keeps. Items are removed one by one as the iterator
+    /// is advanced, so dropping it early (or a panicking predicate) keeps the rest of the list.
+    ///
+    /// # Example
+    /// ```
+    /// use fast_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// list.extend(1..=6);
+    /// let odd = list
+    ///     .extract_if(|_, value| {
+    ///         *value *= 10;
+    ///         *value % 20 != 0
+    ///     })
+    ///     .map(|(_, value)| value)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(odd, vec![10, 30, 50]);
+    /// assert_eq!(list.iter().map(|item| item.value).collect::<Vec<_>>(), vec![20, 40, 60]);
+    /// ```
+    pub fn extract_if<F>(&mut self, f: F) -> ExtractIf<'_, T, F>
     where
-        F: FnMut(&T) -> bool,
-        T: Clone,
-        LinkedListItem<T>: Clone,
+        F: FnMut(LinkedListIndex, &mut T) -> bool,
     {
-        let mut new_list = Self::new();
-        new_list.items = self.items.clone();
-        new_list.head = self.head;
-        new_list.tail = self.tail;
-        new_list.retain_mut(f);
-        new_list
+        ExtractIf {
+            current: self.head,
+            list: self,
+            f,
+        }
+    }
+
+    /// Remove consecutive items for which `f` returns true, merging each one into the item that
+    /// survives before it, and return the indexes of the removed items.
+    /// This operation should compute in O(n) time.
+    ///
+    /// `f` gets the survivor and the candidate for removal, and can move data from the candidate
+    /// into the survivor (e.g. add up counters) before returning true.
+    ///
+    /// # Example
+    /// ```
+    /// use fast_list::LinkedList;
+    ///
+    /// let mut log = LinkedList::new();
+    /// log.extend([("connect", 1), ("retry", 1), ("retry", 1), ("retry", 1), ("ok", 1)]);
+    /// let removed = log.coalesce(|keep, drop| {
+    ///     if keep.0 != drop.0 {
+    ///         return false;
+    ///     }
+    ///     keep.1 += drop.1;
+    ///     true
+    /// });
+    /// assert_eq!(removed.len(), 2);
+    /// assert_eq!(
+    ///     log.iter().map(|item| item.value).collect::<Vec<_>>(),
+    ///     vec![("connect", 1), ("retry", 3), ("ok", 1)]
+    /// );
+    /// ```
+    pub fn coalesce<F>(&mut self, mut f: F) -> Vec<LinkedListIndex>
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        let mut removed = Vec::new();
+        let Some(mut keep) = self.head else {
+            return removed;
+        };
+        while let Some(next) = self.items[keep].next_index {
+            let Some([survivor, candidate]) = self.items.get_disjoint_mut([keep, next]) else {
+                unreachable!("neighbours are distinct items");
+            };
+            if f(&mut survivor.value, &mut candidate.value) {
+                self.remove(next);
+                removed.push(next);
+            } else {
+                keep = next;
+            }
+        }
+        removed
+    }
+
+    /// Remove consecutive items for which `same_bucket` returns true, keeping the first one of
+    /// each run, and return the indexes of the removed items.
+    /// This operation should compute in O(n) time.
+    ///
+    /// Like `Vec::dedup_by`, `same_bucket` gets the candidate for removal first and the item that
+    /// survives before it second.
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F) -> Vec<LinkedListIndex>
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        self.coalesce(|keep, drop| same_bucket(drop, keep))
+    }
+
+    /// Remove consecutive items that resolve to the same key, see [`dedup_by`](Self::dedup_by).
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F) -> Vec<LinkedListIndex>
+    where
+        K: PartialEq,
+        F: FnMut(&mut T) -> K,
+    {
+        self.dedup_by(|a, b| key(a) == key(b))
+    }
+
+    /// Remove consecutive equal items, see [`dedup_by`](Self::dedup_by).
+    pub fn dedup(&mut self) -> Vec<LinkedListIndex>
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b)
+    }
+
+    /// Remove all items and yield their values from head to tail (or tail to head).
+    ///
+    /// Items that were not yielded yet are removed when the iterator is dropped.
+    pub fn drain(&mut self) -> Drain<'_, T> {
+        Drain { list: self }
+    }
+}
+
+/// An iterator that removes the items of a [`LinkedList`] that match a predicate, see
+/// [`LinkedList::extract_if`].
+pub struct ExtractIf<'a, T, F> {
+    list: &'a mut LinkedList<T>,
+    current: Option<LinkedListIndex>,
+    f: F,
+}
+
+impl<T, F> Iterator for ExtractIf<'_, T, F>
+where
+    F: FnMut(LinkedListIndex, &mut T) -> bool,
+{
+    type Item = (LinkedListIndex, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(index) = self.current {
+            let item = &mut self.list.items[index];
+            self.current = item.next_index;
+            if (self.f)(index, &mut item.value) {
+                return self.list.remove(index).map(|item| (index, item.value));
+            }
+        }
+        None
+    }
+}
+
+/// An iterator that removes all items of a [`LinkedList`], see [`LinkedList::drain`].
+pub struct Drain<'a, T> {
+    list: &'a mut LinkedList<T>,
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T> DoubleEndedIterator for Drain<'_, T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Drain<'_, T> {}
+
+impl<T> Drop for Drain<'_, T> {
+    fn drop(&mut self) {
+        while self.list.pop_front().is_some() {}
     }
 }
@@ -0,0 +1,246 @@
+use core::borrow::Borrow;
+use core::hash::Hash;
+
+use hashbrown::HashMap;
+
+use crate::cache::{CachePolicy, CacheStats, EvictionCallback};
+use crate::linked_list::{LinkedList, LinkedListIndex};
+
+/// A least recently used cache.
+///
+/// The recency order is kept in a [`LinkedList`] (most recently used at the front) and a
+/// [`hashbrown::HashMap`] maps every key to its [`LinkedListIndex`], so all operations are O(1).
+///
+/// # Example
+/// ```
+/// use fast_list::LruCache;
+///
+/// let mut cache = LruCache::new(2);
+/// cache.put("a", 1);
+/// cache.put("b", 2);
+/// assert_eq!(cache.get(&"a"), Some(&1));
+///
+/// // "b" is the least recently used entry now, so it is evicted.
+/// cache.put("c", 3);
+/// assert!(cache.peek(&"b").is_none());
+/// assert_eq!(cache.pop_lru(), Some(("a", 1)));
+/// ```
+pub struct LruCache<K, V> {
+    map: HashMap<K, LinkedListIndex>,
+    list: LinkedList<(K, V)>,
+    cap: usize,
+    on_evict: Option<EvictionCallback<K, V>>,
+    stats: CacheStats,
+}
+
+impl<K: Hash + Eq + Clone, V> LruCache<K, V> {
+    /// Create a new empty cache that holds at most `cap` entries.
+    pub fn new(cap: usize) -> Self {
+        Self {
+            map: HashMap::with_capacity(cap),
+            list: LinkedList::new(),
+            cap,
+            on_evict: None,
+            stats: CacheStats::default(),
+        }
+    }
+
+    /// Call `f` with every entry that is evicted because the cache is full (or was resized).
+    ///
+    /// Entries removed with [`pop`](Self::pop), [`pop_lru`](Self::pop_lru) or [`clear`](Self::clear) are not passed to `f`.
+    pub fn with_eviction_callback<F>(mut self, f: F) -> Self
+    where
+        F: FnMut(K, V) + Send + 'static,
+    {
+        self.on_evict = Some(Box::new(f));
+        self
+    }
+
+    /// The maximum number of entries.
+    pub fn cap(&self) -> usize {
+        self.cap
+    }
+
+    /// Get the number of entries.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns true if the cache has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns true if the cache contains the key, without changing its recency.
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(key)
+    }
+
+    /// The hit and miss counters of [`get`](Self::get) and [`get_mut`](Self::get_mut).
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    /// Reset the hit and miss counters.
+    pub fn reset_stats(&mut self) {
+        self.stats = CacheStats::default();
+    }
+
+    /// Get the value of a key and mark it as the most recently used entry.
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.lookup(key)?;
+        self.list.move_to_front(index);
+        self.list.get(index).map(|item| &item.value.1)
+    }
+
+    /// Get a mutable reference to the value of a key and mark it as the most recently used entry.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.lookup(key)?;
+        self.list.move_to_front(index);
+        self.list.get_mut(index).map(|item| &mut item.value.1)
+    }
+
+    /// Get the value of a key without changing its recency.
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = *self.map.get(key)?;
+        self.list.get(index).map(|item| &item.value.1)
+    }
+
+    /// Get the least recently used entry without removing it.
+    pub fn peek_lru(&self) -> Option<(&K, &V)> {
+        self.list.tail().map(|item| (&item.value.0, &item.value.1))
+    }
+
+    /// Insert or update an entry and mark it as the most recently used one.
+    ///
+    /// Returns the old value if the key was already in the cache. If the cache is full,
+    /// the least recently used entry is evicted (and passed to the eviction callback).
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(&index) = self.map.get(&key) {
+            self.list.move_to_front(index);
+            let item = self.list.get_mut(index).unwrap();
+            return Some(std::mem::replace(&mut item.value.1, value));
+        }
+        if self.cap == 0 {
+            self.evict(key, value);
+            return None;
+        }
+        if self.len() >= self.cap {
+            self.evict_lru();
+        }
+        let index = self.list.push_front((key.clone(), value));
+        self.map.insert(key, index);
+        None
+    }
+
+    /// Remove an entry, returning its value if the key was in the cache.
+    pub fn pop<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.map.remove(key)?;
+        self.list.remove(index).map(|item| item.value.1)
+    }
+
+    /// Remove and return the least recently used entry.
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        let (key, value) = self.list.pop_back()?;
+        self.map.remove(&key);
+        Some((key, value))
+    }
+
+    /// Change the capacity of the cache, evicting the least recently used entries that no longer fit.
+    pub fn resize(&mut self, cap: usize) {
+        while self.len() > cap {
+            self.evict_lru();
+        }
+        self.cap = cap;
+    }
+
+    /// Remove all entries.
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.list.clear();
+    }
+
+    /// Returns an iterator over the entries from the most to the least recently used one.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.list
+            .head
+            .into_iter()
+            .flat_map(move |head| self.list.iter_next(head))
+            .map(|item| {
+                let (key, value) = item.value();
+                (key, value)
+            })
+    }
+
+    fn lookup<Q>(&mut self, key: &Q) -> Option<LinkedListIndex>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.map.get(key).copied();
+        self.stats.record(index.is_some());
+        index
+    }
+
+    fn evict_lru(&mut self) {
+        if let Some((key, value)) = self.pop_lru() {
+            self.evict(key, value);
+        }
+    }
+
+    fn evict(&mut self, key: K, value: V) {
+        if let Some(on_evict) = &mut self.on_evict {
+            on_evict(key, value);
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, V> CachePolicy<K, V> for LruCache<K, V> {
+    fn get(&mut self, key: &K) -> Option<&V> {
+        LruCache::get(self, key)
+    }
+
+    fn peek(&self, key: &K) -> Option<&V> {
+        LruCache::peek(self, key)
+    }
+
+    fn put(&mut self, key: K, value: V) -> Option<V> {
+        LruCache::put(self, key, value)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        self.pop(key)
+    }
+
+    fn len(&self) -> usize {
+        LruCache::len(self)
+    }
+
+    fn cap(&self) -> usize {
+        LruCache::cap(self)
+    }
+
+    fn stats(&self) -> CacheStats {
+        LruCache::stats(self)
+    }
+}
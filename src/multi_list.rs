@@ -0,0 +1,293 @@
+use core::fmt;
+use slotmap::SlotMap;
+
+use crate::linked_list::LinkedListIndex;
+
+/// The links of a node in one of the lists of a [`MultiLinkedList`].
+#[derive(Debug, Clone, Copy, Default)]
+struct Link {
+    prev: Option<LinkedListIndex>,
+    next: Option<LinkedListIndex>,
+    linked: bool,
+}
+
+#[derive(Debug)]
+struct MultiLinkedItem<T, const L: usize> {
+    value: T,
+    links: [Link; L],
+}
+
+/// `L` independent doubly linked lists threaded through a single SlotMap of nodes.
+///
+/// Every node carries one `(prev, next)` link pair per list, so the same [`LinkedListIndex`]
+/// identifies a value in all of the lists it is a member of, and a node can be linked into or
+/// unlinked from each list in O(1) without touching the others. Lists are numbered `0..L`,
+/// passing a list number of `L` or more panics.
+///
+/// # Example
+/// ```
+/// use fast_list::MultiLinkedList;
+///
+/// const BY_OWNER: usize = 0;
+/// const RECENT: usize = 1;
+///
+/// let mut entities = MultiLinkedList::<&str, 2>::new();
+/// let a = entities.insert("a");
+/// let b = entities.insert("b");
+/// entities.push_back(BY_OWNER, a);
+/// entities.push_back(BY_OWNER, b);
+/// entities.push_front(RECENT, a);
+/// entities.push_front(RECENT, b);
+///
+/// // Touching "a" only changes its position in the recency list.
+/// entities.move_to_front(RECENT, a);
+/// assert_eq!(entities.iter(RECENT).map(|(_, v)| *v).collect::<Vec<_>>(), vec!["a", "b"]);
+/// assert_eq!(entities.iter(BY_OWNER).map(|(_, v)| *v).collect::<Vec<_>>(), vec!["a", "b"]);
+///
+/// // Removing the entity unlinks it from every list.
+/// assert_eq!(entities.remove(a), Some("a"));
+/// assert_eq!(entities.head(RECENT), Some(b));
+/// assert_eq!(entities.len_of(BY_OWNER), 1);
+/// ```
+pub struct MultiLinkedList<T, const L: usize> {
+    items: SlotMap<LinkedListIndex, MultiLinkedItem<T, L>>,
+    heads: [Option<LinkedListIndex>; L],
+    tails: [Option<LinkedListIndex>; L],
+    lens: [usize; L],
+}
+
+impl<T: fmt::Debug, const L: usize> fmt::Debug for MultiLinkedList<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.items.values()).finish()
+    }
+}
+
+impl<T, const L: usize> Default for MultiLinkedList<T, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const L: usize> MultiLinkedList<T, L> {
+    /// Create a new empty set of lists.
+    pub fn new() -> Self {
+        Self {
+            items: SlotMap::with_key(),
+            heads: [None; L],
+            tails: [None; L],
+            lens: [0; L],
+        }
+    }
+
+    /// Get the number of nodes, whether they are linked into any list or not.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true if there are no nodes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Get the number of nodes in list `l`.
+    pub fn len_of(&self, l: usize) -> usize {
+        self.lens[l]
+    }
+
+    /// Checks if the given index exists.
+    pub fn contains_key(&self, index: LinkedListIndex) -> bool {
+        self.items.contains_key(index)
+    }
+
+    /// Returns true if the node is a member of list `l`.
+    pub fn is_linked(&self, l: usize, index: LinkedListIndex) -> bool {
+        self.items
+            .get(index)
+            .map(|item| item.links[l].linked)
+            .unwrap_or(false)
+    }
+
+    /// The index of the first node in list `l`.
+    pub fn head(&self, l: usize) -> Option<LinkedListIndex> {
+        self.heads[l]
+    }
+
+    /// The index of the last node in list `l`.
+    pub fn tail(&self, l: usize) -> Option<LinkedListIndex> {
+        self.tails[l]
+    }
+
+    /// The index of the node after the given one in list `l`.
+    pub fn next(&self, l: usize, index: LinkedListIndex) -> Option<LinkedListIndex> {
+        self.items.get(index).and_then(|item| item.links[l].next)
+    }
+
+    /// The index of the node before the given one in list `l`.
+    pub fn prev(&self, l: usize, index: LinkedListIndex) -> Option<LinkedListIndex> {
+        self.items.get(index).and_then(|item| item.links[l].prev)
+    }
+
+    /// Get the value of a node.
+    pub fn get(&self, index: LinkedListIndex) -> Option<&T> {
+        self.items.get(index).map(|item| &item.value)
+    }
+
+    /// Get a mutable reference to the value of a node.
+    pub fn get_mut(&mut self, index: LinkedListIndex) -> Option<&mut T> {
+        self.items.get_mut(index).map(|item| &mut item.value)
+    }
+
+    /// Add a node that is not a member of any list yet and return its index.
+    pub fn insert(&mut self, value: T) -> LinkedListIndex {
+        self.items.insert(MultiLinkedItem {
+            value,
+            links: [Link::default(); L],
+        })
+    }
+
+    /// Remove a node from all lists it is a member of and return its value.
+    pub fn remove(&mut self, index: LinkedListIndex) -> Option<T> {
+        if !self.items.contains_key(index) {
+            return None;
+        }
+        for l in 0..L {
+            self.unlink(l, index);
+        }
+        self.items.remove(index).map(|item| item.value)
+    }
+
+    /// Link a node to the back of list `l`.
+    ///
+    /// Returns false if the index does not exist or is already a member of the list.
+    pub fn push_back(&mut self, l: usize, index: LinkedListIndex) -> bool {
+        let tail = self.tails[l];
+        self.link(l, index, tail, None)
+    }
+
+    /// Link a node to the front of list `l`.
+    ///
+    /// Returns false if the index does not exist or is already a member of the list.
+    pub fn push_front(&mut self, l: usize, index: LinkedListIndex) -> bool {
+        let head = self.heads[l];
+        self.link(l, index, None, head)
+    }
+
+    /// Link a node into list `l` right after `after`.
+    ///
+    /// Returns false if either index does not exist, `after` is not a member of the list
+    /// or `index` already is.
+    pub fn insert_after(
+        &mut self,
+        l: usize,
+        index: LinkedListIndex,
+        after: LinkedListIndex,
+    ) -> bool {
+        if !self.is_linked(l, after) {
+            return false;
+        }
+        let next = self.next(l, after);
+        self.link(l, index, Some(after), next)
+    }
+
+    /// Link a node into list `l` right before `before`.
+    ///
+    /// Returns false if either index does not exist, `before` is not a member of the list
+    /// or `index` already is.
+    pub fn insert_before(
+        &mut self,
+        l: usize,
+        index: LinkedListIndex,
+        before: LinkedListIndex,
+    ) -> bool {
+        if !self.is_linked(l, before) {
+            return false;
+        }
+        let prev = self.prev(l, before);
+        self.link(l, index, prev, Some(before))
+    }
+
+    /// Remove a node from list `l` only, it stays in the other lists.
+    ///
+    /// Returns false if the index does not exist or is not a member of the list.
+    pub fn unlink(&mut self, l: usize, index: LinkedListIndex) -> bool {
+        let Some(item) = self.items.get_mut(index) else {
+            return false;
+        };
+        let link = std::mem::take(&mut item.links[l]);
+        if !link.linked {
+            return false;
+        }
+
+        match link.prev {
+            Some(prev) => self.items[prev].links[l].next = link.next,
+            None => self.heads[l] = link.next,
+        }
+        match link.next {
+            Some(next) => self.items[next].links[l].prev = link.prev,
+            None => self.tails[l] = link.prev,
+        }
+        self.lens[l] -= 1;
+        true
+    }
+
+    /// Move a node to the front of list `l`, linking it if it was not a member yet.
+    ///
+    /// Returns false if the index does not exist.
+    pub fn move_to_front(&mut self, l: usize, index: LinkedListIndex) -> bool {
+        self.unlink(l, index);
+        self.push_front(l, index)
+    }
+
+    /// Move a node to the back of list `l`, linking it if it was not a member yet.
+    ///
+    /// Returns false if the index does not exist.
+    pub fn move_to_back(&mut self, l: usize, index: LinkedListIndex) -> bool {
+        self.unlink(l, index);
+        self.push_back(l, index)
+    }
+
+    /// Returns an iterator over the indexes and values of list `l` from head to tail.
+    pub fn iter(&self, l: usize) -> impl Iterator<Item = (LinkedListIndex, &T)> {
+        std::iter::successors(self.heads[l], move |index| self.items[*index].links[l].next)
+            .map(move |index| (index, &self.items[index].value))
+    }
+
+    /// Returns an iterator over the indexes and values of list `l` from tail to head.
+    pub fn iter_rev(&self, l: usize) -> impl Iterator<Item = (LinkedListIndex, &T)> {
+        std::iter::successors(self.tails[l], move |index| self.items[*index].links[l].prev)
+            .map(move |index| (index, &self.items[index].value))
+    }
+
+    /// Link a node in between `prev` and `next`, which must be adjacent in list `l`.
+    fn link(
+        &mut self,
+        l: usize,
+        index: LinkedListIndex,
+        prev: Option<LinkedListIndex>,
+        next: Option<LinkedListIndex>,
+    ) -> bool {
+        match self.items.get_mut(index) {
+            Some(item) if !item.links[l].linked => {
+                item.links[l] = Link {
+                    prev,
+                    next,
+                    linked: true,
+                };
+            }
+            _ => return false,
+        }
+
+        match prev {
+            Some(prev) => self.items[prev].links[l].next = Some(index),
+            None => self.heads[l] = Some(index),
+        }
+        match next {
+            Some(next) => self.items[next].links[l].prev = Some(index),
+            None => self.tails[l] = Some(index),
+        }
+        self.lens[l] += 1;
+        true
+    }
+}
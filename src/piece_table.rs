@@ -0,0 +1,389 @@
+use core::fmt;
+use slotmap::{new_key_type, SecondaryMap, SlotMap};
+use std::ops::Range;
+
+use crate::linked_list::{LinkedList, LinkedListIndex};
+
+new_key_type! {
+    /// A handle of a mark in a [`PieceTable`].
+    pub struct MarkIndex;
+}
+
+/// The buffer a [`Piece`] points into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PieceSource {
+    /// The text the table was created with, which is never modified.
+    Original,
+    /// The append-only buffer with all inserted text.
+    Add,
+}
+
+/// A span of one of the buffers of a [`PieceTable`], in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Piece {
+    /// The buffer the text is in.
+    pub source: PieceSource,
+    /// The offset of the text in the buffer.
+    pub start: usize,
+    /// The length of the text.
+    pub len: usize,
+}
+
+/// Where a mark is: an offset within a piece, or None for the end of the text.
+#[derive(Debug, Clone, Copy)]
+struct Anchor {
+    piece: Option<LinkedListIndex>,
+    offset: usize,
+}
+
+/// A text buffer that represents its contents as a [`LinkedList`] of pieces of two buffers.
+///
+/// The original text is never modified and inserted text is only appended to the add buffer, so
+/// an edit splits at most two pieces and links or unlinks a few list items. A piece keeps its
+/// [`LinkedListIndex`] when it is split (the new piece gets the second half), so anything
+/// attached to a piece handle survives edits elsewhere.
+///
+/// Marks are anchored to a piece and an offset within it, and follow the text around them: text
+/// inserted at a mark goes before it, and a mark in deleted text moves to the start of the
+/// deletion. All offsets are in bytes and must be on char boundaries.
+///
+/// # Example
+/// ```
+/// use fast_list::PieceTable;
+///
+/// let mut text = PieceTable::new("hello world");
+/// let mark = text.add_mark(6);
+/// text.insert(5, ",");
+/// text.insert(0, "> ");
+/// assert_eq!(text.to_string(), "> hello, world");
+/// assert_eq!(text.mark_offset(mark), Some(9));
+///
+/// text.delete(2..9);
+/// text.insert(2, "big\n");
+/// assert_eq!(text.to_string(), "> big\nworld");
+/// assert_eq!(text.line_at(1), Some("world".to_string()));
+/// assert_eq!(text.mark_offset(mark), Some(6));
+/// ```
+pub struct PieceTable {
+    original: String,
+    add: String,
+    pieces: LinkedList<Piece>,
+    len: usize,
+    marks: SlotMap<MarkIndex, Anchor>,
+    /// The marks anchored to each piece, so splitting or removing a piece only visits its own marks.
+    piece_marks: SecondaryMap<LinkedListIndex, Vec<MarkIndex>>,
+}
+
+impl fmt::Debug for PieceTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PieceTable")
+            .field("pieces", &self.pieces)
+            .field("text", &self.to_string())
+            .finish()
+    }
+}
+
+impl fmt::Display for PieceTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.chunks().try_for_each(|chunk| f.write_str(chunk))
+    }
+}
+
+impl Default for PieceTable {
+    fn default() -> Self {
+        Self::new(String::new())
+    }
+}
+
+impl PieceTable {
+    /// Create a table with the given original text.
+    pub fn new(original: impl Into<String>) -> Self {
+        let original = original.into();
+        let len = original.len();
+        let mut pieces = LinkedList::new();
+        if len > 0 {
+            pieces.push_back(Piece {
+                source: PieceSource::Original,
+                start: 0,
+                len,
+            });
+        }
+        Self {
+            original,
+            add: String::new(),
+            pieces,
+            len,
+            marks: SlotMap::with_key(),
+            piece_marks: SecondaryMap::new(),
+        }
+    }
+
+    /// Get the length of the text in bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the text is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The list of pieces that make up the text, in order.
+    pub fn pieces(&self) -> &LinkedList<Piece> {
+        &self.pieces
+    }
+
+    /// Get the text of a piece.
+    pub fn piece_text(&self, index: LinkedListIndex) -> Option<&str> {
+        self.pieces.get(index).map(|item| self.text_of(&item.value))
+    }
+
+    /// Returns an iterator over the text of the pieces, in order.
+    pub fn chunks(&self) -> impl Iterator<Item = &str> {
+        self.pieces
+            .head
+            .into_iter()
+            .flat_map(move |head| self.pieces.iter_next(head))
+            .map(move |item| self.text_of(item.value()))
+    }
+
+    /// Find the piece that contains the byte at `offset`, and the offset within that piece.
+    ///
+    /// Returns None if the offset is at or past the end of the text.
+    pub fn piece_at(&self, offset: usize) -> Option<(LinkedListIndex, usize)> {
+        let mut start = 0;
+        let mut current = self.pieces.head;
+        while let Some(index) = current {
+            let len = self.pieces.get(index).unwrap().value.len;
+            if offset < start + len {
+                return Some((index, offset - start));
+            }
+            start += len;
+            current = self.pieces.cursor_next(index);
+        }
+        None
+    }
+
+    /// Insert text at a byte offset.
+    ///
+    /// # Panics
+    /// Panics if the offset is past the end of the text or not on a char boundary.
+    pub fn insert(&mut self, offset: usize, text: &str) {
+        self.assert_char_boundary(offset);
+        if text.is_empty() {
+            return;
+        }
+        let start = self.add.len();
+        self.add.push_str(text);
+        self.len += text.len();
+
+        let next = self.split_at(offset);
+        let prev = match next {
+            Some(next) => self.pieces.get(next).unwrap().prev_index,
+            None => self.pieces.tail,
+        };
+
+        // Typing extends the piece that was added right before, instead of adding a piece per edit.
+        if let Some(prev) = prev {
+            let piece = &mut self.pieces.get_mut(prev).unwrap().value;
+            if piece.source == PieceSource::Add && piece.start + piece.len == start {
+                piece.len += text.len();
+                return;
+            }
+        }
+        let piece = Piece {
+            source: PieceSource::Add,
+            start,
+            len: text.len(),
+        };
+        match next {
+            Some(next) => self.pieces.insert_before(next, piece),
+            None => self.pieces.push_back(piece),
+        };
+    }
+
+    /// Delete the text in a range of byte offsets.
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds or does not start and end on char boundaries.
+    pub fn delete(&mut self, range: Range<usize>) {
+        assert!(range.start <= range.end, "range start is after its end");
+        self.assert_char_boundary(range.start);
+        self.assert_char_boundary(range.end);
+        if range.is_empty() {
+            return;
+        }
+
+        let end = self.split_at(range.end);
+        let mut current = self.split_at(range.start);
+        while current != end {
+            let index = current.unwrap();
+            current = self.pieces.cursor_next(index);
+            self.pieces.remove(index);
+            self.move_marks(index, end);
+        }
+        self.len -= range.len();
+    }
+
+    /// Returns the number of lines, which is one more than the number of newlines.
+    pub fn line_count(&self) -> usize {
+        1 + self
+            .chunks()
+            .map(|chunk| chunk.bytes().filter(|byte| *byte == b'\n').count())
+            .sum::<usize>()
+    }
+
+    /// Returns the text of a line (counting from 0) without its newline.
+    pub fn line_at(&self, line: usize) -> Option<String> {
+        let mut current = 0;
+        let mut text = String::new();
+        for chunk in self.chunks() {
+            for (i, part) in chunk.split('\n').enumerate() {
+                if i > 0 {
+                    if current == line {
+                        return Some(text);
+                    }
+                    current += 1;
+                }
+                if current == line {
+                    text.push_str(part);
+                }
+            }
+        }
+        (current == line).then_some(text)
+    }
+
+    /// Add a mark at a byte offset and return its handle.
+    ///
+    /// # Panics
+    /// Panics if the offset is past the end of the text or not on a char boundary.
+    pub fn add_mark(&mut self, offset: usize) -> MarkIndex {
+        self.assert_char_boundary(offset);
+        let anchor = match self.piece_at(offset) {
+            Some((piece, offset)) => Anchor {
+                piece: Some(piece),
+                offset,
+            },
+            None => Anchor {
+                piece: None,
+                offset: 0,
+            },
+        };
+        let mark = self.marks.insert(anchor);
+        if let Some(piece) = anchor.piece {
+            self.attach(mark, piece);
+        }
+        mark
+    }
+
+    /// Remove a mark, returning false if it did not exist.
+    pub fn remove_mark(&mut self, mark: MarkIndex) -> bool {
+        let Some(anchor) = self.marks.remove(mark) else {
+            return false;
+        };
+        if let Some(marks) = anchor
+            .piece
+            .and_then(|piece| self.piece_marks.get_mut(piece))
+        {
+            marks.retain(|other| *other != mark);
+        }
+        true
+    }
+
+    /// Get the current byte offset of a mark.
+    pub fn mark_offset(&self, mark: MarkIndex) -> Option<usize> {
+        let anchor = self.marks.get(mark)?;
+        let Some(piece) = anchor.piece else {
+            return Some(self.len);
+        };
+        let before = self
+            .pieces
+            .iter_prev(piece)
+            .skip(1)
+            .map(|item| item.value.len)
+            .sum::<usize>();
+        Some(before + anchor.offset)
+    }
+
+    /// Split the piece containing `offset` so that a piece starts there, and return that piece.
+    ///
+    /// Returns None if the offset is at the end of the text.
+    fn split_at(&mut self, offset: usize) -> Option<LinkedListIndex> {
+        let (index, at) = self.piece_at(offset)?;
+        if at == 0 {
+            return Some(index);
+        }
+        let piece = &mut self.pieces.get_mut(index).unwrap().value;
+        let rest = Piece {
+            source: piece.source,
+            start: piece.start + at,
+            len: piece.len - at,
+        };
+        piece.len = at;
+        let new = self.pieces.insert_after(index, rest);
+
+        // Marks in the second half move to the new piece.
+        if let Some(marks) = self.piece_marks.get_mut(index) {
+            let mut moved = Vec::new();
+            marks.retain(|mark| {
+                let anchor = &mut self.marks[*mark];
+                if anchor.offset < at {
+                    return true;
+                }
+                anchor.piece = Some(new);
+                anchor.offset -= at;
+                moved.push(*mark);
+                false
+            });
+            if !moved.is_empty() {
+                self.piece_marks.insert(new, moved);
+            }
+        }
+        Some(new)
+    }
+
+    /// Move the marks of a removed piece to the start of `to`, or to the end of the text.
+    fn move_marks(&mut self, from: LinkedListIndex, to: Option<LinkedListIndex>) {
+        let Some(marks) = self.piece_marks.remove(from) else {
+            return;
+        };
+        for mark in marks {
+            self.marks[mark] = Anchor {
+                piece: to,
+                offset: 0,
+            };
+            if let Some(to) = to {
+                self.attach(mark, to);
+            }
+        }
+    }
+
+    fn attach(&mut self, mark: MarkIndex, piece: LinkedListIndex) {
+        match self.piece_marks.get_mut(piece) {
+            Some(marks) => marks.push(mark),
+            None => {
+                self.piece_marks.insert(piece, vec![mark]);
+            }
+        }
+    }
+
+    fn text_of(&self, piece: &Piece) -> &str {
+        let buffer = match piece.source {
+            PieceSource::Original => &self.original,
+            PieceSource::Add => &self.add,
+        };
+        &buffer[piece.start..piece.start + piece.len]
+    }
+
+    fn assert_char_boundary(&self, offset: usize) {
+        assert!(offset <= self.len, "offset is past the end of the text");
+        if let Some((piece, at)) = self.piece_at(offset) {
+            assert!(
+                self.piece_text(piece).unwrap().is_char_boundary(at),
+                "offset is not on a char boundary"
+            );
+        }
+    }
+}
@@ -0,0 +1,270 @@
+use core::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+use crate::linked_list::{LinkedList, LinkedListIndex};
+
+/// A value that was pushed to a closed [`ListQueue`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("the queue is closed")]
+pub struct QueueClosed<T> {
+    /// The rejected value.
+    pub value: T,
+}
+
+struct QueueState<T> {
+    items: LinkedList<T>,
+    /// The wakers of the pending [`Pop`] futures, so dropping a future can deregister it in O(1).
+    wakers: LinkedList<Waker>,
+    closed: bool,
+}
+
+/// A multi-producer multi-consumer FIFO queue backed by a [`LinkedList`].
+///
+/// Consumers can block on [`pop_blocking`](Self::pop_blocking) or
+/// [`pop_timeout`](Self::pop_timeout), or await [`pop`](Self::pop) on any async runtime.
+/// Every pushed value gets a [`LinkedListIndex`] that can be used to [`cancel`](Self::cancel)
+/// it while it is still queued.
+///
+/// After [`close`](Self::close) pushes are rejected, while consumers keep receiving the values
+/// that are still queued and then get None.
+///
+/// # Example
+/// ```
+/// use fast_list::ListQueue;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// let queue = Arc::new(ListQueue::new());
+/// let consumer = {
+///     let queue = queue.clone();
+///     thread::spawn(move || {
+///         let mut received = Vec::new();
+///         while let Some(value) = queue.pop_blocking() {
+///             received.push(value);
+///         }
+///         received
+///     })
+/// };
+///
+/// queue.push(1).unwrap();
+/// let cancelled = queue.push(2).unwrap();
+/// queue.push(3).unwrap();
+/// let _ = queue.cancel(cancelled);
+/// queue.close();
+///
+/// let received = consumer.join().unwrap();
+/// assert!(received == vec![1, 3] || received == vec![1, 2, 3]);
+/// assert!(queue.push(4).is_err());
+/// ```
+pub struct ListQueue<T> {
+    state: Mutex<QueueState<T>>,
+    not_empty: Condvar,
+}
+
+impl<T: fmt::Debug> fmt::Debug for ListQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.lock();
+        f.debug_struct("ListQueue")
+            .field("items", &state.items)
+            .field("closed", &state.closed)
+            .finish()
+    }
+}
+
+impl<T> Default for ListQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> ListQueue<T> {
+    /// Create a new empty, open queue.
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(QueueState {
+                items: LinkedList::new(),
+                wakers: LinkedList::new(),
+                closed: false,
+            }),
+            not_empty: Condvar::new(),
+        }
+    }
+
+    /// Get the number of queued values.
+    pub fn len(&self) -> usize {
+        self.lock().items.len()
+    }
+
+    /// Returns true if no values are queued.
+    pub fn is_empty(&self) -> bool {
+        self.lock().items.is_empty()
+    }
+
+    /// Returns true if the queue was closed.
+    pub fn is_closed(&self) -> bool {
+        self.lock().closed
+    }
+
+    /// Add a value to the back of the queue and wake up one waiting consumer.
+    ///
+    /// Returns the index of the value, or the value itself if the queue is closed.
+    pub fn push(&self, value: T) -> Result<LinkedListIndex, QueueClosed<T>> {
+        let mut state = self.lock();
+        if state.closed {
+            return Err(QueueClosed { value });
+        }
+        let index = state.items.push_back(value);
+        let waker = state.wakers.pop_front();
+        drop(state);
+
+        self.not_empty.notify_one();
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+        Ok(index)
+    }
+
+    /// Remove the value at the front of the queue without waiting.
+    pub fn try_pop(&self) -> Option<T> {
+        self.lock().items.pop_front()
+    }
+
+    /// Remove the value at the front of the queue, blocking until there is one.
+    ///
+    /// Returns None once the queue is closed and empty.
+    pub fn pop_blocking(&self) -> Option<T> {
+        let mut state = self.lock();
+        loop {
+            if let Some(value) = state.items.pop_front() {
+                return Some(value);
+            }
+            if state.closed {
+                return None;
+            }
+            state = self
+                .not_empty
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+
+    /// Remove the value at the front of the queue, blocking for at most `timeout`.
+    ///
+    /// Returns None if the timeout passed or the queue is closed and empty.
+    pub fn pop_timeout(&self, timeout: Duration) -> Option<T> {
+        let deadline = Instant::now() + timeout;
+        let mut state = self.lock();
+        loop {
+            if let Some(value) = state.items.pop_front() {
+                return Some(value);
+            }
+            let now = Instant::now();
+            if state.closed || now >= deadline {
+                return None;
+            }
+            state = self
+                .not_empty
+                .wait_timeout(state, deadline - now)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+    }
+
+    /// Returns a future that removes the value at the front of the queue, waiting until there is one.
+    ///
+    /// The future resolves to None once the queue is closed and empty. It works with any async
+    /// runtime, and dropping it before it resolves loses no values.
+    pub fn pop(&self) -> Pop<'_, T> {
+        Pop {
+            queue: self,
+            waker: None,
+        }
+    }
+
+    /// Remove a value that is still queued, returning it if it was not popped or cancelled yet.
+    pub fn cancel(&self, index: LinkedListIndex) -> Option<T> {
+        self.lock().items.remove(index).map(|item| item.value)
+    }
+
+    /// Close the queue: further pushes are rejected and waiting consumers are woken up.
+    ///
+    /// Values that are still queued can be popped until the queue is empty.
+    pub fn close(&self) {
+        let mut state = self.lock();
+        state.closed = true;
+        let wakers = std::iter::from_fn(|| state.wakers.pop_front()).collect::<Vec<_>>();
+        drop(state);
+
+        self.not_empty.notify_all();
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+
+    /// Remove all queued values at once, in order.
+    pub fn drain(&self) -> Vec<T> {
+        // Pop one by one instead of swapping the list out, so old indexes never match new values.
+        let mut state = self.lock();
+        std::iter::from_fn(|| state.items.pop_front()).collect()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, QueueState<T>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// The future returned by [`ListQueue::pop`].
+#[must_use = "futures do nothing unless polled"]
+pub struct Pop<'a, T> {
+    queue: &'a ListQueue<T>,
+    /// The index of our waker while we are waiting.
+    waker: Option<LinkedListIndex>,
+}
+
+impl<T> fmt::Debug for Pop<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pop").field("waker", &self.waker).finish()
+    }
+}
+
+impl<T> Future for Pop<'_, T> {
+    type Output = Option<T>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.queue.lock();
+        let value = state.items.pop_front();
+        if value.is_some() || state.closed {
+            if let Some(waker) = self.waker.take() {
+                state.wakers.remove(waker);
+            }
+            return Poll::Ready(value);
+        }
+
+        match self.waker.and_then(|waker| state.wakers.get_mut(waker)) {
+            Some(item) => item.value.clone_from(cx.waker()),
+            None => self.waker = Some(state.wakers.push_back(cx.waker().clone())),
+        }
+        Poll::Pending
+    }
+}
+
+impl<T> Drop for Pop<'_, T> {
+    fn drop(&mut self) {
+        let Some(waker) = self.waker else {
+            return;
+        };
+        let mut state = self.queue.lock();
+        // If a push already woke us up, pass the wake-up on so the value is not left waiting.
+        if state.wakers.remove(waker).is_none() && !state.items.is_empty() {
+            if let Some(next) = state.wakers.pop_front() {
+                drop(state);
+                next.wake();
+            }
+        }
+    }
+}
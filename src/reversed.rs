@@ -0,0 +1,150 @@
+use crate::linked_list::{ItemRef, LinkedList, LinkedListIndex, LinkedListItem};
+
+/// A read-only view of a [`LinkedList`] that presents it from the tail to the head.
+///
+/// Creating the view is O(1) and doesn't touch any items: heads are tails, next is previous and
+/// the other way around. The items it returns are the items of the underlying list, so their own
+/// `next_index` and `prev_index` still point in the original direction.
+///
+/// # Example
+/// ```
+/// use fast_list::LinkedList;
+///
+/// let mut list = LinkedList::new();
+/// let indexes = list.extend([1, 2, 3, 4]);
+///
+/// let reversed = list.reversed();
+/// assert_eq!(reversed.iter().map(|item| item.value).collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+/// assert_eq!(reversed.head, Some(indexes[3]));
+/// assert_eq!(reversed.cursor_next(indexes[2]), Some(indexes[1]));
+/// assert_eq!(reversed.nth(1), Some(indexes[2]));
+/// ```
+#[derive(Debug)]
+pub struct Reversed<'a, T> {
+    /// The index of the first item of the view, the tail of the list.
+    pub head: Option<LinkedListIndex>,
+    /// The index of the last item of the view, the head of the list.
+    pub tail: Option<LinkedListIndex>,
+    list: &'a LinkedList<T>,
+}
+
+impl<T> Clone for Reversed<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Reversed<'_, T> {}
+
+impl<T> LinkedList<T> {
+    /// Returns a view of the list from the tail to the head, see [`Reversed`].
+    pub fn reversed(&self) -> Reversed<'_, T> {
+        Reversed {
+            head: self.tail,
+            tail: self.head,
+            list: self,
+        }
+    }
+}
+
+impl<'a, T> Reversed<'a, T> {
+    /// The underlying list, in its original order.
+    pub fn list(&self) -> &'a LinkedList<T> {
+        self.list
+    }
+
+    /// Get the number of items.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns true if there are no items.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Checks if the list contains the given index.
+    pub fn contains_key(&self, index: LinkedListIndex) -> bool {
+        self.list.contains_key(index)
+    }
+
+    /// Get the first item of the view, which is the tail of the list.
+    pub fn head(&self) -> Option<&'a LinkedListItem<T>> {
+        self.list.tail()
+    }
+
+    /// Get the last item of the view, which is the head of the list.
+    pub fn tail(&self) -> Option<&'a LinkedListItem<T>> {
+        self.list.head()
+    }
+
+    /// Get an item.
+    pub fn get(&self, index: LinkedListIndex) -> Option<&'a LinkedListItem<T>> {
+        self.list.get(index)
+    }
+
+    /// Get the item after the given one in the view, which is the one before it in the list.
+    pub fn next_of(&self, index: LinkedListIndex) -> Option<&'a LinkedListItem<T>> {
+        self.list.prev_of(index)
+    }
+
+    /// Get the item before the given one in the view, which is the one after it in the list.
+    pub fn prev_of(&self, index: LinkedListIndex) -> Option<&'a LinkedListItem<T>> {
+        self.list.next_of(index)
+    }
+
+    /// Returns the index of the item after the given one in the view.
+    pub fn cursor_next(&self, index: LinkedListIndex) -> Option<LinkedListIndex> {
+        self.list.cursor_prev(index)
+    }
+
+    /// Returns the index of the item before the given one in the view.
+    pub fn cursor_prev(&self, index: LinkedListIndex) -> Option<LinkedListIndex> {
+        self.list.cursor_next(index)
+    }
+
+    /// Returns an iterator over the indexes of the view, starting at the given index.
+    pub fn cursor_iter_next(
+        &self,
+        start: LinkedListIndex,
+    ) -> impl Iterator<Item = LinkedListIndex> + 'a {
+        self.list.cursor_iter_prev(start)
+    }
+
+    /// Returns an iterator over the indexes of the view in reverse order, starting at the given index.
+    pub fn cursor_iter_prev(
+        &self,
+        start: LinkedListIndex,
+    ) -> impl Iterator<Item = LinkedListIndex> + 'a {
+        self.list.cursor_iter_next(start)
+    }
+
+    /// Returns an iterator over the items of the view, from the tail of the list to the head.
+    pub fn iter(&self) -> impl Iterator<Item = ItemRef<'a, T>> {
+        let list = self.list;
+        self.head
+            .into_iter()
+            .flat_map(move |head| list.iter_prev(head))
+    }
+
+    /// Returns an iterator over the items of the view, starting at the given index.
+    pub fn iter_next(&self, start: LinkedListIndex) -> impl Iterator<Item = ItemRef<'a, T>> {
+        self.list.iter_prev(start)
+    }
+
+    /// Returns an iterator over the items of the view in reverse order, starting at the given index.
+    pub fn iter_prev(&self, start: LinkedListIndex) -> impl Iterator<Item = ItemRef<'a, T>> {
+        self.list.iter_next(start)
+    }
+
+    /// Returns the index of the nth item of the view, iterating from whichever end is closer.
+    pub fn nth(&self, n: usize) -> Option<LinkedListIndex> {
+        let len = self.len();
+        if n >= len {
+            return None;
+        }
+        self.list.nth(len - n - 1)
+    }
+}
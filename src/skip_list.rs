@@ -0,0 +1,553 @@
+use core::borrow::Borrow;
+use core::fmt;
+use core::ops::{Bound, RangeBounds};
+use slotmap::SecondaryMap;
+
+use crate::linked_list::{LinkedList, LinkedListIndex};
+
+/// The maximum number of express lanes above the bottom list.
+const MAX_HEIGHT: usize = 32;
+
+/// A node of an express lane.
+struct LaneNode {
+    /// The index of the entry in the bottom list.
+    base: LinkedListIndex,
+    /// The index of the node one level down, in the lane below or in the bottom list.
+    down: LinkedListIndex,
+}
+
+/// An ordered map backed by a skip list.
+///
+/// The entries are kept sorted by key in a bottom [`LinkedList`], so every entry has a stable
+/// [`LinkedListIndex`] handle and the list can be walked with the usual cursors through
+/// [`list`](Self::list). On top of it sit express lanes (also [`LinkedList`]s) that skip over
+/// ever larger stretches of the bottom list, which makes searching, inserting and removing O(log n)
+/// on average.
+///
+/// # Example
+/// ```
+/// use fast_list::SkipListMap;
+///
+/// let mut map = SkipListMap::new();
+/// map.insert(3, "c");
+/// map.insert(1, "a");
+/// map.insert(2, "b");
+///
+/// assert_eq!(map.get(&2), Some(&"b"));
+/// assert_eq!(map.range(2..).map(|(k, _)| *k).collect::<Vec<_>>(), vec![2, 3]);
+///
+/// // Handles stay valid while other entries come and go.
+/// let handle = map.get_index(&3).unwrap();
+/// map.remove(&1);
+/// map.insert(0, "z");
+/// assert_eq!(map.get_by_index(handle), Some((&3, &"c")));
+/// ```
+pub struct SkipListMap<K, V> {
+    base: LinkedList<(K, V)>,
+    /// The express lanes, the lowest lane first.
+    lanes: Vec<LinkedList<LaneNode>>,
+    /// The lane nodes of every entry that has any, the lowest lane first.
+    towers: SecondaryMap<LinkedListIndex, Vec<LinkedListIndex>>,
+    /// The xorshift state used to pick the height of new entries.
+    rng: u64,
+}
+
+impl<K: Ord + fmt::Debug, V: fmt::Debug> fmt::Debug for SkipListMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Ord, V> Default for SkipListMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> SkipListMap<K, V> {
+    /// Create a new empty map.
+    pub fn new() -> Self {
+        Self {
+            base: LinkedList::new(),
+            lanes: Vec::new(),
+            towers: SecondaryMap::new(),
+            rng: 0x9e37_79b9_7f4a_7c15,
+        }
+    }
+
+    /// Get the number of entries.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.base.len()
+    }
+
+    /// Returns true if the map has no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.base.is_empty()
+    }
+
+    /// The sorted bottom list, whose items are `(key, value)` pairs.
+    pub fn list(&self) -> &LinkedList<(K, V)> {
+        &self.base
+    }
+
+    /// Returns true if the map contains the key.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.get_index(key).is_some()
+    }
+
+    /// Get the value of a key.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.get_index(key)?;
+        Some(&self.base.get(index)?.value.1)
+    }
+
+    /// Get a mutable reference to the value of a key.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.get_index(key)?;
+        Some(&mut self.base.get_mut(index)?.value.1)
+    }
+
+    /// Get the stable index of the entry of a key.
+    pub fn get_index<Q>(&self, key: &Q) -> Option<LinkedListIndex>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.lower_bound(key)
+            .filter(|index| self.key_of(*index).borrow() == key)
+    }
+
+    /// Get the entry with the given index.
+    pub fn get_by_index(&self, index: LinkedListIndex) -> Option<(&K, &V)> {
+        self.base
+            .get(index)
+            .map(|item| (&item.value.0, &item.value.1))
+    }
+
+    /// Get a mutable reference to the value of the entry with the given index.
+    pub fn get_by_index_mut(&mut self, index: LinkedListIndex) -> Option<&mut V> {
+        self.base.get_mut(index).map(|item| &mut item.value.1)
+    }
+
+    /// The index of the first entry with a key greater than or equal to `key`.
+    pub fn lower_bound<Q>(&self, key: &Q) -> Option<LinkedListIndex>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let pred = self.search(|k| k.borrow() < key, |_, _| {});
+        self.base_next(pred)
+    }
+
+    /// The index of the first entry with a key greater than `key`.
+    pub fn upper_bound<Q>(&self, key: &Q) -> Option<LinkedListIndex>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let pred = self.search(|k| k.borrow() <= key, |_, _| {});
+        self.base_next(pred)
+    }
+
+    /// Insert an entry, keeping the entries sorted.
+    ///
+    /// Returns the old value if the key was already in the map, the entry keeps its index in that case.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let mut preds = vec![None; self.lanes.len()];
+        let pred = self.search(|k| *k < key, |level, pred| preds[level] = pred);
+        if let Some(next) = self.base_next(pred) {
+            if *self.key_of(next) == key {
+                let item = self.base.get_mut(next).unwrap();
+                return Some(std::mem::replace(&mut item.value.1, value));
+            }
+        }
+
+        let index = match pred {
+            Some(pred) => self.base.insert_after(pred, (key, value)),
+            None => self.base.push_front((key, value)),
+        };
+
+        let height = self.random_height();
+        let mut tower = Vec::with_capacity(height);
+        let mut down = index;
+        for level in 0..height {
+            if level == self.lanes.len() {
+                self.lanes.push(LinkedList::new());
+                preds.push(None);
+            }
+            let lane = &mut self.lanes[level];
+            let node = LaneNode { base: index, down };
+            down = match preds[level] {
+                Some(pred) => lane.insert_after(pred, node),
+                None => lane.push_front(node),
+            };
+            tower.push(down);
+        }
+        if !tower.is_empty() {
+            self.towers.insert(index, tower);
+        }
+        None
+    }
+
+    /// Remove the entry of a key, returning its value if the key was in the map.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Remove the entry of a key, returning the key and value if the key was in the map.
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.get_index(key)?;
+        self.remove_by_index(index)
+    }
+
+    /// Remove the entry with the given index, returning its key and value if the index exists.
+    pub fn remove_by_index(&mut self, index: LinkedListIndex) -> Option<(K, V)> {
+        let item = self.base.remove(index)?;
+        if let Some(tower) = self.towers.remove(index) {
+            for (level, node) in tower.into_iter().enumerate() {
+                self.lanes[level].remove(node);
+            }
+            while matches!(self.lanes.last(), Some(lane) if lane.is_empty()) {
+                self.lanes.pop();
+            }
+        }
+        Some(item.value)
+    }
+
+    /// Get the entry with the smallest key.
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.base.head().map(|item| (&item.value.0, &item.value.1))
+    }
+
+    /// Get the entry with the largest key.
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.base.tail().map(|item| (&item.value.0, &item.value.1))
+    }
+
+    /// Remove and return the entry with the smallest key.
+    pub fn pop_first(&mut self) -> Option<(K, V)> {
+        self.remove_by_index(self.base.head?)
+    }
+
+    /// Remove and return the entry with the largest key.
+    pub fn pop_last(&mut self) -> Option<(K, V)> {
+        self.remove_by_index(self.base.tail?)
+    }
+
+    /// Remove all entries.
+    pub fn clear(&mut self) {
+        self.base.clear();
+        self.lanes.clear();
+        self.towers.clear();
+    }
+
+    /// Returns an iterator over the entries in ascending key order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.iter_from(self.base.head)
+    }
+
+    /// Returns an iterator over the keys in ascending order.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(key, _)| key)
+    }
+
+    /// Returns an iterator over the values in ascending key order.
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    /// Returns an iterator over the entries with keys in the given range, in ascending key order.
+    pub fn range<Q, R>(&self, range: R) -> impl Iterator<Item = (&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(start) => self.lower_bound(start),
+            Bound::Excluded(start) => self.upper_bound(start),
+            Bound::Unbounded => self.base.head,
+        };
+        self.iter_from(start)
+            .take_while(move |(key, _)| match range.end_bound() {
+                Bound::Included(end) => (*key).borrow() <= end,
+                Bound::Excluded(end) => (*key).borrow() < end,
+                Bound::Unbounded => true,
+            })
+    }
+
+    fn iter_from(&self, start: Option<LinkedListIndex>) -> impl Iterator<Item = (&K, &V)> {
+        start
+            .into_iter()
+            .flat_map(move |start| self.base.iter_next(start))
+            .map(|item| {
+                let (key, value) = item.value();
+                (key, value)
+            })
+    }
+
+    fn key_of(&self, index: LinkedListIndex) -> &K {
+        &self.base.get(index).unwrap().value.0
+    }
+
+    fn base_next(&self, pred: Option<LinkedListIndex>) -> Option<LinkedListIndex> {
+        match pred {
+            Some(pred) => self.base.cursor_next(pred),
+            None => self.base.head,
+        }
+    }
+
+    /// Find the last entry whose key is `before` the searched key, going down the express lanes.
+    ///
+    /// `record` is called with the last node visited in every lane, which is where a new entry
+    /// would have to be linked in after.
+    fn search<F, R>(&self, before: F, mut record: R) -> Option<LinkedListIndex>
+    where
+        F: Fn(&K) -> bool,
+        R: FnMut(usize, Option<LinkedListIndex>),
+    {
+        let mut current: Option<LinkedListIndex> = None;
+        for (level, lane) in self.lanes.iter().enumerate().rev() {
+            loop {
+                let next = match current {
+                    Some(current) => lane.cursor_next(current),
+                    None => lane.head,
+                };
+                match next {
+                    Some(next) if before(self.key_of(lane.get(next).unwrap().value.base)) => {
+                        current = Some(next)
+                    }
+                    _ => break,
+                }
+            }
+            record(level, current);
+            current = current.map(|current| lane.get(current).unwrap().value.down);
+        }
+
+        loop {
+            match self.base_next(current) {
+                Some(next) if before(self.key_of(next)) => current = Some(next),
+                _ => return current,
+            }
+        }
+    }
+
+    /// Pick the number of express lanes for a new entry, each lane with half the chance of the one below.
+    fn random_height(&mut self) -> usize {
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+        (self.rng.trailing_ones() as usize).min(MAX_HEIGHT)
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for SkipListMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Ord, V> Extend<(K, V)> for SkipListMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+/// An ordered set backed by a [`SkipListMap`] with `()` values.
+///
+/// # Example
+/// ```
+/// use fast_list::SkipListSet;
+///
+/// let mut set = SkipListSet::from_iter([5, 1, 3]);
+/// assert!(!set.insert(3));
+/// assert_eq!(set.iter().copied().collect::<Vec<_>>(), vec![1, 3, 5]);
+///
+/// let index = set.lower_bound(&2).unwrap();
+/// assert_eq!(set.get_by_index(index), Some(&3));
+/// ```
+pub struct SkipListSet<T> {
+    map: SkipListMap<T, ()>,
+}
+
+impl<T: Ord + fmt::Debug> fmt::Debug for SkipListSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Ord> Default for SkipListSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> SkipListSet<T> {
+    /// Create a new empty set.
+    pub fn new() -> Self {
+        Self {
+            map: SkipListMap::new(),
+        }
+    }
+
+    /// Get the number of values.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns true if the set has no values.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// The sorted bottom list, whose items are `(value, ())` pairs.
+    pub fn list(&self) -> &LinkedList<(T, ())> {
+        self.map.list()
+    }
+
+    /// Returns true if the set contains the value.
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.map.contains_key(value)
+    }
+
+    /// Get the stable index of a value.
+    pub fn get_index<Q>(&self, value: &Q) -> Option<LinkedListIndex>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.map.get_index(value)
+    }
+
+    /// Get the value with the given index.
+    pub fn get_by_index(&self, index: LinkedListIndex) -> Option<&T> {
+        self.map.get_by_index(index).map(|(value, _)| value)
+    }
+
+    /// The index of the first value greater than or equal to `value`.
+    pub fn lower_bound<Q>(&self, value: &Q) -> Option<LinkedListIndex>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.map.lower_bound(value)
+    }
+
+    /// The index of the first value greater than `value`.
+    pub fn upper_bound<Q>(&self, value: &Q) -> Option<LinkedListIndex>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.map.upper_bound(value)
+    }
+
+    /// Add a value, returning false if it was already in the set.
+    pub fn insert(&mut self, value: T) -> bool {
+        self.map.insert(value, ()).is_none()
+    }
+
+    /// Remove a value, returning true if it was in the set.
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.map.remove(value).is_some()
+    }
+
+    /// Remove a value and return it, if it was in the set.
+    pub fn take<Q>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.map.remove_entry(value).map(|(value, _)| value)
+    }
+
+    /// Get the smallest value.
+    pub fn first(&self) -> Option<&T> {
+        self.map.first().map(|(value, _)| value)
+    }
+
+    /// Get the largest value.
+    pub fn last(&self) -> Option<&T> {
+        self.map.last().map(|(value, _)| value)
+    }
+
+    /// Remove and return the smallest value.
+    pub fn pop_first(&mut self) -> Option<T> {
+        self.map.pop_first().map(|(value, _)| value)
+    }
+
+    /// Remove and return the largest value.
+    pub fn pop_last(&mut self) -> Option<T> {
+        self.map.pop_last().map(|(value, _)| value)
+    }
+
+    /// Remove all values.
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// Returns an iterator over the values in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.map.keys()
+    }
+
+    /// Returns an iterator over the values in the given range, in ascending order.
+    pub fn range<Q, R>(&self, range: R) -> impl Iterator<Item = &T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        self.map.range(range).map(|(value, _)| value)
+    }
+}
+
+impl<T: Ord> FromIterator<T> for SkipListSet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Ord> Extend<T> for SkipListSet<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
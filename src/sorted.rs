@@ -0,0 +1,210 @@
+use core::cmp::Ordering;
+use slotmap::SecondaryMap;
+
+use crate::linked_list::{LinkedList, LinkedListIndex};
+
+impl<T> LinkedList<T> {
+    /// Insert a value into a list that is sorted according to `compare`, and return its index.
+    ///
+    /// The scan for the insertion point starts at `hint` and walks towards the head or the tail
+    /// depending on how the value compares, so a hint close to the final position makes this cheap.
+    /// Without a valid hint the scan walks in from the head and the tail at the same time, so it
+    /// only takes as long as the walk from the closer end. Values that compare equal to existing
+    /// ones are inserted after them, which keeps the insertion order of equal values.
+    ///
+    /// # Example
+    /// ```
+    /// use fast_list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new();
+    /// list.extend([1, 3, 5]);
+    /// let index = list.insert_sorted_by(4, None, |a, b| a.cmp(b));
+    /// list.insert_sorted_by(2, Some(index), |a, b| a.cmp(b));
+    /// assert_eq!(list.iter().map(|item| item.value).collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+    /// ```
+    pub fn insert_sorted_by<F>(
+        &mut self,
+        value: T,
+        hint: Option<LinkedListIndex>,
+        mut compare: F,
+    ) -> LinkedListIndex
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let Some(start) = hint.filter(|hint| self.contains_key(*hint)) else {
+            return self.insert_sorted_from_ends(value, compare);
+        };
+
+        if compare(&value, &self.get(start).unwrap().value) == Ordering::Less {
+            // Walk towards the head until an item is not greater than the value.
+            let mut before = start;
+            while let Some(prev) = self.get(before).unwrap().prev_index {
+                if compare(&value, &self.get(prev).unwrap().value) != Ordering::Less {
+                    return self.insert_after(prev, value);
+                }
+                before = prev;
+            }
+            self.insert_before(before, value)
+        } else {
+            // Walk towards the tail until an item is greater than the value.
+            let mut after = start;
+            while let Some(next) = self.cursor_next(after) {
+                if compare(&value, &self.get(next).unwrap().value) == Ordering::Less {
+                    return self.insert_before(next, value);
+                }
+                after = next;
+            }
+            self.insert_after(after, value)
+        }
+    }
+
+    /// Insert a value into a sorted list by walking in from the head and the tail in turns,
+    /// stopping at whichever end reaches the insertion point first.
+    fn insert_sorted_from_ends<F>(&mut self, value: T, mut compare: F) -> LinkedListIndex
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let (Some(mut front), Some(mut back)) = (self.head, self.tail) else {
+            return self.push_back(value);
+        };
+        if compare(&value, &self.get(front).unwrap().value) == Ordering::Less {
+            return self.insert_before(front, value);
+        }
+        if compare(&value, &self.get(back).unwrap().value) != Ordering::Less {
+            return self.insert_after(back, value);
+        }
+
+        // The head is not greater than the value and the tail is, so neither walk runs off the list.
+        loop {
+            front = self.cursor_next(front).unwrap();
+            if compare(&value, &self.get(front).unwrap().value) == Ordering::Less {
+                return self.insert_before(front, value);
+            }
+            back = self.cursor_prev(back).unwrap();
+            if compare(&value, &self.get(back).unwrap().value) != Ordering::Less {
+                return self.insert_after(back, value);
+            }
+        }
+    }
+
+    /// Insert a value into a list that is sorted by the key extracted with `f`, see
+    /// [`insert_sorted_by`](Self::insert_sorted_by).
+    pub fn insert_sorted_by_key<K, F>(
+        &mut self,
+        value: T,
+        hint: Option<LinkedListIndex>,
+        mut f: F,
+    ) -> LinkedListIndex
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.insert_sorted_by(value, hint, |a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Insert a value into a sorted list, see [`insert_sorted_by`](Self::insert_sorted_by).
+    pub fn insert_sorted(&mut self, value: T, hint: Option<LinkedListIndex>) -> LinkedListIndex
+    where
+        T: Ord,
+    {
+        self.insert_sorted_by(value, hint, T::cmp)
+    }
+
+    /// Merge another list that is sorted according to `compare` into this sorted list in a single pass.
+    /// This operation should compute in O(n + m) time.
+    ///
+    /// The items of this list are only relinked, so they keep their indexes. Every list has its own
+    /// storage though, so the items of `other` can't keep theirs: each one is moved (not cloned)
+    /// into a new slot of this list, which is O(1) per item, and the returned map holds one entry
+    /// per moved item to translate its old index into the new one. On ties the items of this list
+    /// come first.
+    pub fn merge_sorted_by<F>(
+        &mut self,
+        mut other: LinkedList<T>,
+        mut compare: F,
+    ) -> SecondaryMap<LinkedListIndex, LinkedListIndex>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut remap = SecondaryMap::new();
+        let mut cursor = self.head;
+        while let Some(old) = other.head {
+            let value = other.pop_front().unwrap();
+            while let Some(current) = cursor {
+                if compare(&value, &self.get(current).unwrap().value) == Ordering::Less {
+                    break;
+                }
+                cursor = self.cursor_next(current);
+            }
+            let new = match cursor {
+                Some(current) => self.insert_before(current, value),
+                None => self.push_back(value),
+            };
+            remap.insert(old, new);
+        }
+        remap
+    }
+
+    /// Merge another sorted list into this sorted list, see [`merge_sorted_by`](Self::merge_sorted_by).
+    ///
+    /// # Example
+    /// ```
+    /// use fast_list::LinkedList;
+    ///
+    /// let mut a = LinkedList::new();
+    /// a.extend([1, 4, 6]);
+    /// let mut b = LinkedList::new();
+    /// let five = b.extend([2, 5])[1];
+    ///
+    /// let remap = a.merge_sorted(b);
+    /// assert_eq!(a.iter().map(|item| item.value).collect::<Vec<_>>(), vec![1, 2, 4, 5, 6]);
+    /// assert_eq!(a.get(remap[five]).unwrap().value, 5);
+    /// ```
+    pub fn merge_sorted(
+        &mut self,
+        other: LinkedList<T>,
+    ) -> SecondaryMap<LinkedListIndex, LinkedListIndex>
+    where
+        T: Ord,
+    {
+        self.merge_sorted_by(other, T::cmp)
+    }
+
+    /// Returns true if every pair of adjacent values is in order according to `in_order`.
+    pub fn is_sorted_by<F>(&self, mut in_order: F) -> bool
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut current = self.head;
+        while let Some(index) = current {
+            let next = self.cursor_next(index);
+            if let Some(next) = next {
+                if !in_order(
+                    &self.get(index).unwrap().value,
+                    &self.get(next).unwrap().value,
+                ) {
+                    return false;
+                }
+            }
+            current = next;
+        }
+        true
+    }
+
+    /// Returns true if the keys extracted with `f` are in ascending order.
+    pub fn is_sorted_by_key<K, F>(&self, mut f: F) -> bool
+    where
+        K: PartialOrd,
+        F: FnMut(&T) -> K,
+    {
+        self.is_sorted_by(|a, b| f(a) <= f(b))
+    }
+
+    /// Returns true if the values are in ascending order.
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.is_sorted_by(|a, b| a <= b)
+    }
+}
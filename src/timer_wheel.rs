@@ -0,0 +1,339 @@
+use core::fmt;
+use slotmap::{new_key_type, SlotMap};
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+new_key_type! {
+    /// A handle of a timer scheduled on a [`TimerWheel`], used to cancel it.
+    pub struct TimerHandle;
+}
+
+/// The number of slots per level, every level covers 64 times the range of the level below.
+const SLOT_BITS: u32 = 6;
+const SLOTS: usize = 1 << SLOT_BITS;
+/// Enough levels to cover every `u64` deadline.
+const LEVELS: usize = 11;
+/// The extra list for timers that were already due when they were scheduled.
+const DUE: usize = LEVELS * SLOTS;
+
+/// A source of the current time in ticks for a [`TimerWheel`].
+pub trait Clock {
+    /// The current time in ticks.
+    fn now(&self) -> u64;
+}
+
+/// A [`Clock`] based on [`Instant`] that counts ticks of a fixed duration since it was created.
+#[derive(Debug, Clone, Copy)]
+pub struct SystemClock {
+    start: Instant,
+    tick: Duration,
+}
+
+impl SystemClock {
+    /// Create a clock that starts at tick 0 now.
+    ///
+    /// # Panics
+    /// Panics if the tick duration is zero.
+    pub fn new(tick: Duration) -> Self {
+        assert!(!tick.is_zero(), "the tick duration must not be zero");
+        Self {
+            start: Instant::now(),
+            tick,
+        }
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> u64 {
+        (self.start.elapsed().as_nanos() / self.tick.as_nanos()) as u64
+    }
+}
+
+/// A [`Clock`] that only moves when told to, for tests.
+#[derive(Debug, Default, Clone)]
+pub struct MockClock {
+    now: Cell<u64>,
+}
+
+impl MockClock {
+    /// Create a clock at the given tick.
+    pub fn new(now: u64) -> Self {
+        Self {
+            now: Cell::new(now),
+        }
+    }
+
+    /// Set the current tick.
+    pub fn set(&self, now: u64) {
+        self.now.set(now);
+    }
+
+    /// Move the clock forward by a number of ticks.
+    pub fn advance(&self, ticks: u64) {
+        self.now.set(self.now.get() + ticks);
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> u64 {
+        self.now.get()
+    }
+}
+
+#[derive(Debug)]
+struct TimerEntry<T> {
+    value: T,
+    deadline: u64,
+    slot: usize,
+    prev: Option<TimerHandle>,
+    next: Option<TimerHandle>,
+}
+
+/// A hierarchical timer wheel.
+///
+/// Timers live in a shared SlotMap arena and every slot of the wheel is a doubly linked list
+/// threaded through it, so scheduling and cancelling are O(1) and handles stay valid while
+/// timers cascade from the coarse levels down to the fine ones. Each level has 64 slots and
+/// covers 64 times the range of the level below it.
+///
+/// Time is measured in ticks, either passed to [`advance`](Self::advance) directly or read from
+/// a [`Clock`] with [`poll`](Self::poll).
+///
+/// # Example
+/// ```
+/// use fast_list::{MockClock, TimerWheel};
+///
+/// let clock = MockClock::new(0);
+/// let mut wheel = TimerWheel::new();
+/// wheel.schedule(10, "a");
+/// let b = wheel.schedule(5_000, "b");
+/// wheel.schedule(300, "c");
+///
+/// clock.set(500);
+/// assert_eq!(wheel.poll(&clock).collect::<Vec<_>>(), vec!["a", "c"]);
+///
+/// assert_eq!(wheel.cancel(b), Some("b"));
+/// clock.advance(10_000);
+/// assert_eq!(wheel.poll(&clock).count(), 0);
+/// ```
+pub struct TimerWheel<T> {
+    entries: SlotMap<TimerHandle, TimerEntry<T>>,
+    heads: Vec<Option<TimerHandle>>,
+    tails: Vec<Option<TimerHandle>>,
+    occupied: [u64; LEVELS],
+    now: u64,
+}
+
+impl<T: fmt::Debug> fmt::Debug for TimerWheel<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.entries
+                    .values()
+                    .map(|entry| (entry.deadline, &entry.value)),
+            )
+            .finish()
+    }
+}
+
+impl<T> Default for TimerWheel<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> TimerWheel<T> {
+    /// Create a new empty wheel starting at tick 0.
+    pub fn new() -> Self {
+        Self::starting_at(0)
+    }
+
+    /// Create a new empty wheel starting at the given tick.
+    pub fn starting_at(now: u64) -> Self {
+        Self {
+            entries: SlotMap::with_key(),
+            heads: vec![None; DUE + 1],
+            tails: vec![None; DUE + 1],
+            occupied: [0; LEVELS],
+            now,
+        }
+    }
+
+    /// Get the number of scheduled timers.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if there are no scheduled timers.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The tick the wheel has been advanced to.
+    pub fn now(&self) -> u64 {
+        self.now
+    }
+
+    /// Checks if the timer is still scheduled.
+    pub fn contains_key(&self, handle: TimerHandle) -> bool {
+        self.entries.contains_key(handle)
+    }
+
+    /// Get the deadline of a scheduled timer.
+    pub fn deadline(&self, handle: TimerHandle) -> Option<u64> {
+        self.entries.get(handle).map(|entry| entry.deadline)
+    }
+
+    /// Get the value of a scheduled timer.
+    pub fn get(&self, handle: TimerHandle) -> Option<&T> {
+        self.entries.get(handle).map(|entry| &entry.value)
+    }
+
+    /// Get a mutable reference to the value of a scheduled timer.
+    pub fn get_mut(&mut self, handle: TimerHandle) -> Option<&mut T> {
+        self.entries.get_mut(handle).map(|entry| &mut entry.value)
+    }
+
+    /// Schedule a value to be returned by the first advance to `deadline` or later.
+    ///
+    /// A deadline that is not after [`now`](Self::now) fires on the next advance.
+    pub fn schedule(&mut self, deadline: u64, value: T) -> TimerHandle {
+        let handle = self.entries.insert(TimerEntry {
+            value,
+            deadline,
+            slot: DUE,
+            prev: None,
+            next: None,
+        });
+        self.link(handle);
+        handle
+    }
+
+    /// Schedule a value `delay` ticks after [`now`](Self::now).
+    pub fn schedule_after(&mut self, delay: u64, value: T) -> TimerHandle {
+        self.schedule(self.now.saturating_add(delay), value)
+    }
+
+    /// Cancel a timer, returning its value if it had not fired or been cancelled yet.
+    pub fn cancel(&mut self, handle: TimerHandle) -> Option<T> {
+        if !self.entries.contains_key(handle) {
+            return None;
+        }
+        self.unlink(handle);
+        self.entries.remove(handle).map(|entry| entry.value)
+    }
+
+    /// Advance the wheel to `now` and return the values of the timers that are due, in order of
+    /// their deadlines. Timers that were scheduled in the past come first, in the order they were
+    /// scheduled.
+    ///
+    /// Moving backwards does nothing. Skipping ahead is cheap: only occupied slots are visited.
+    pub fn advance(&mut self, now: u64) -> impl Iterator<Item = T> {
+        let mut fired = Vec::new();
+        self.drain_slot(DUE, &mut fired);
+
+        while let Some((level, slot, start)) = self.next_slot() {
+            if start > now {
+                break;
+            }
+            self.now = self.now.max(start);
+            self.drain_slot(level * SLOTS + slot, &mut fired);
+        }
+        self.now = self.now.max(now);
+        fired.into_iter()
+    }
+
+    /// Advance the wheel to the current time of a clock, see [`advance`](Self::advance).
+    pub fn poll<C: Clock>(&mut self, clock: &C) -> impl Iterator<Item = T> {
+        self.advance(clock.now())
+    }
+
+    /// Find the occupied slot that starts first, returning its level, slot and start tick.
+    fn next_slot(&self) -> Option<(usize, usize, u64)> {
+        let mut next = None;
+        for (level, occupied) in self.occupied.iter().enumerate() {
+            let shift = level as u32 * SLOT_BITS;
+            let current = (self.now >> shift) as usize % SLOTS;
+            // Slots before the current one are always empty, deadlines are never in the past.
+            let ahead = occupied & (!0 << current);
+            if ahead == 0 {
+                continue;
+            }
+            let slot = ahead.trailing_zeros() as usize;
+            let bits = shift + SLOT_BITS;
+            let level_start = if bits < 64 {
+                self.now & !((1 << bits) - 1)
+            } else {
+                0
+            };
+            let start = level_start + ((slot as u64) << shift);
+            if !matches!(next, Some((_, _, earliest)) if earliest <= start) {
+                next = Some((level, slot, start));
+            }
+        }
+        next
+    }
+
+    /// Unlink every timer of a slot, firing the due ones and rescheduling the rest on a finer level.
+    fn drain_slot(&mut self, slot: usize, fired: &mut Vec<T>) {
+        let mut current = self.heads[slot].take();
+        self.tails[slot] = None;
+        if slot < DUE {
+            self.occupied[slot / SLOTS] &= !(1 << (slot % SLOTS));
+        }
+
+        while let Some(handle) = current {
+            current = self.entries[handle].next;
+            if self.entries[handle].deadline <= self.now {
+                fired.push(self.entries.remove(handle).unwrap().value);
+            } else {
+                self.link(handle);
+            }
+        }
+    }
+
+    /// Link a timer to the back of the slot its deadline belongs to.
+    fn link(&mut self, handle: TimerHandle) {
+        let deadline = self.entries[handle].deadline;
+        let slot = if deadline <= self.now {
+            DUE
+        } else {
+            // The level is picked by the highest bit in which the deadline differs from now.
+            let significant = 63 - (self.now ^ deadline).leading_zeros();
+            let level = (significant / SLOT_BITS) as usize;
+            let slot = (deadline >> (level as u32 * SLOT_BITS)) as usize % SLOTS;
+            self.occupied[level] |= 1 << slot;
+            level * SLOTS + slot
+        };
+
+        let tail = self.tails[slot];
+        let entry = &mut self.entries[handle];
+        entry.slot = slot;
+        entry.prev = tail;
+        entry.next = None;
+        match tail {
+            Some(tail) => self.entries[tail].next = Some(handle),
+            None => self.heads[slot] = Some(handle),
+        }
+        self.tails[slot] = Some(handle);
+    }
+
+    /// Unlink a timer from its slot.
+    fn unlink(&mut self, handle: TimerHandle) {
+        let entry = &mut self.entries[handle];
+        let (slot, prev, next) = (entry.slot, entry.prev.take(), entry.next.take());
+        match prev {
+            Some(prev) => self.entries[prev].next = next,
+            None => self.heads[slot] = next,
+        }
+        match next {
+            Some(next) => self.entries[next].prev = prev,
+            None => self.tails[slot] = prev,
+        }
+        if slot < DUE && self.heads[slot].is_none() {
+            self.occupied[slot / SLOTS] &= !(1 << (slot % SLOTS));
+        }
+    }
+}
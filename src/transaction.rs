@@ -0,0 +1,380 @@
+use slotmap::SparseSecondaryMap;
+
+use crate::events::ListEventKind;
+use crate::linked_list::{ItemRef, LinkedList, LinkedListIndex, LinkedListItem};
+
+/// A change made to a list by a committed transaction, in the order the changes were made.
+///
+/// Indexes refer to the list the transaction ran on. `prev` and `next` are the neighbours
+/// of the item right after the change, which is enough to replay the change on a replica
+/// that keeps a mapping from these indexes to its own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change<T> {
+    /// An item holding `value` was inserted in between `prev` and `next`.
+    Insert {
+        index: LinkedListIndex,
+        prev: Option<LinkedListIndex>,
+        next: Option<LinkedListIndex>,
+        value: T,
+    },
+    /// An item was removed.
+    Remove { index: LinkedListIndex },
+    /// An item was moved in between `prev` and `next`.
+    Move {
+        index: LinkedListIndex,
+        prev: Option<LinkedListIndex>,
+        next: Option<LinkedListIndex>,
+    },
+    /// The value of an item was changed to `value`.
+    Update { index: LinkedListIndex, value: T },
+}
+
+/// The changes made by a committed transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeSet<T> {
+    /// The changes in the order they were made.
+    pub changes: Vec<Change<T>>,
+}
+
+impl<T> ChangeSet<T> {
+    /// Returns true if the transaction did not change anything.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Get the number of changes.
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Returns an iterator over the changes in the order they were made.
+    pub fn iter(&self) -> impl Iterator<Item = &Change<T>> {
+        self.changes.iter()
+    }
+}
+
+impl<T> IntoIterator for ChangeSet<T> {
+    type Item = Change<T>;
+    type IntoIter = std::vec::IntoIter<Change<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.changes.into_iter()
+    }
+}
+
+/// What has to be done to roll back a single change.
+enum Undo<T> {
+    /// Remove the inserted item.
+    Insert(LinkedListIndex),
+    /// Link the detached item back in between `prev` and `next`.
+    Remove {
+        index: LinkedListIndex,
+        prev: Option<LinkedListIndex>,
+        next: Option<LinkedListIndex>,
+    },
+    /// Move the item back in between `prev` and `next`.
+    Move {
+        index: LinkedListIndex,
+        prev: Option<LinkedListIndex>,
+        next: Option<LinkedListIndex>,
+    },
+    /// Put the old value back.
+    Update { index: LinkedListIndex, value: T },
+}
+
+/// The mutating (and reading) API of a list inside [`LinkedList::transaction`].
+///
+/// Removed items are only detached from the list until the transaction commits,
+/// so rolling back restores them with their original indexes.
+///
+/// If the transaction is dropped without finishing, for example because the closure panicked,
+/// all of its changes are rolled back.
+pub struct Transaction<'a, T> {
+    list: &'a mut LinkedList<T>,
+    undo: Vec<Undo<T>>,
+    changes: Vec<Change<T>>,
+    /// Items that were removed by this transaction but are still in the storage of the list.
+    detached: SparseSecondaryMap<LinkedListIndex, ()>,
+}
+
+impl<T: Clone> LinkedList<T> {
+    /// Run a group of edits that either all apply or, if `f` returns an error, none of them do.
+    ///
+    /// On success the value returned by `f` is returned together with the [`ChangeSet`] describing the edits.
+    /// On error every change is rolled back, including head and tail, and all indexes are the same as before.
+    ///
+    /// # Example
+    /// ```
+    /// use fast_list::{Change, LinkedList};
+    ///
+    /// let mut list = LinkedList::new();
+    /// let indexes = list.extend(0..3);
+    ///
+    /// let result: Result<((), _), &str> = list.transaction(|tx| {
+    ///     tx.remove(indexes[0]);
+    ///     tx.push_back(3);
+    ///     Err("something went wrong")
+    /// });
+    /// assert!(result.is_err());
+    /// assert_eq!(list.head, Some(indexes[0]));
+    /// assert_eq!(list.len(), 3);
+    ///
+    /// let (_, changes) = list
+    ///     .transaction(|tx| Ok::<_, ()>(tx.remove(indexes[0])))
+    ///     .unwrap();
+    /// assert_eq!(changes.changes, vec![Change::Remove { index: indexes[0] }]);
+    /// assert_eq!(list.head, Some(indexes[1]));
+    /// ```
+    pub fn transaction<R, E, F>(&mut self, f: F) -> Result<(R, ChangeSet<T>), E>
+    where
+        F: FnOnce(&mut Transaction<'_, T>) -> Result<R, E>,
+    {
+        let mut tx = Transaction {
+            list: self,
+            undo: Vec::new(),
+            changes: Vec::new(),
+            detached: SparseSecondaryMap::new(),
+        };
+        match f(&mut tx) {
+            Ok(result) => Ok((result, tx.commit())),
+            Err(error) => {
+                tx.rollback();
+                Err(error)
+            }
+        }
+    }
+}
+
+impl<'a, T: Clone> Transaction<'a, T> {
+    /// Checks if the list contains the given index.
+    pub fn contains_key(&self, index: LinkedListIndex) -> bool {
+        self.list.contains_key(index) && !self.detached.contains_key(index)
+    }
+
+    /// Get an item in the list.
+    pub fn get(&self, index: LinkedListIndex) -> Option<&LinkedListItem<T>> {
+        if self.detached.contains_key(index) {
+            return None;
+        }
+        self.list.get(index)
+    }
+
+    /// Get the first item in the list.
+    pub fn head(&self) -> Option<&LinkedListItem<T>> {
+        self.list.head()
+    }
+
+    /// Get the last item in the list.
+    pub fn tail(&self) -> Option<&LinkedListItem<T>> {
+        self.list.tail()
+    }
+
+    /// Get the number of items in the list.
+    pub fn len(&self) -> usize {
+        self.list.len() - self.detached.len()
+    }
+
+    /// Returns true if the list contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator that iterates over the items of the list.
+    pub fn iter(&self) -> impl Iterator<Item = ItemRef<'_, T>> {
+        self.list
+            .head
+            .into_iter()
+            .flat_map(move |head| self.list.iter_next(head))
+    }
+
+    /// Add an item to the back of the list and return its index.
+    pub fn push_back(&mut self, value: T) -> LinkedListIndex {
+        let index = self.list.push_back(value);
+        self.inserted(index);
+        index
+    }
+
+    /// Push an item to the front of the list and return its index.
+    pub fn push_front(&mut self, value: T) -> LinkedListIndex {
+        let index = self.list.push_front(value);
+        self.inserted(index);
+        index
+    }
+
+    /// Insert an item after the given index and return the index of the new item.
+    pub fn insert_after(&mut self, index: LinkedListIndex, value: T) -> LinkedListIndex {
+        self.assert_live(index);
+        let index = self.list.insert_after(index, value);
+        self.inserted(index);
+        index
+    }
+
+    /// Insert an item before the given index and return the index of the new item.
+    pub fn insert_before(&mut self, index: LinkedListIndex, value: T) -> LinkedListIndex {
+        self.assert_live(index);
+        let index = self.list.insert_before(index, value);
+        self.inserted(index);
+        index
+    }
+
+    /// Remove an item from the list, returning its value if it exists.
+    pub fn remove(&mut self, index: LinkedListIndex) -> Option<T> {
+        if !self.contains_key(index) {
+            return None;
+        }
+        let (prev, next) = self.list.unlink(index)?;
+        self.list.emit(ListEventKind::Remove, index, prev, next);
+        self.detached.insert(index, ());
+        self.undo.push(Undo::Remove { index, prev, next });
+        self.changes.push(Change::Remove { index });
+        self.list.get(index).map(|item| item.value.clone())
+    }
+
+    /// Remove the last item in the list and return it (if it exists)
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.list.tail.and_then(|tail| self.remove(tail))
+    }
+
+    /// Remove the first item in the list and return it (if it exists)
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.list.head.and_then(|head| self.remove(head))
+    }
+
+    /// Move an item so that it comes directly after `after`, see [`LinkedList::move_after`].
+    pub fn move_after(&mut self, index: LinkedListIndex, after: LinkedListIndex) -> bool {
+        if !self.contains_key(index) || !self.contains_key(after) {
+            return false;
+        }
+        let from = self.neighbours(index);
+        let moved = self.list.move_after(index, after);
+        if moved {
+            self.moved(index, from);
+        }
+        moved
+    }
+
+    /// Move an item so that it comes directly before `before`, see [`LinkedList::move_before`].
+    pub fn move_before(&mut self, index: LinkedListIndex, before: LinkedListIndex) -> bool {
+        if !self.contains_key(index) || !self.contains_key(before) {
+            return false;
+        }
+        let from = self.neighbours(index);
+        let moved = self.list.move_before(index, before);
+        if moved {
+            self.moved(index, from);
+        }
+        moved
+    }
+
+    /// Replace the value of an item, returning the old value if the index exists.
+    pub fn replace(&mut self, index: LinkedListIndex, value: T) -> Option<T> {
+        if !self.contains_key(index) {
+            return None;
+        }
+        let old = self.list.replace(index, value.clone())?;
+        self.undo.push(Undo::Update {
+            index,
+            value: old.clone(),
+        });
+        self.changes.push(Change::Update { index, value });
+        Some(old)
+    }
+
+    /// Change the value of an item in place. Returns false if the index does not exist.
+    pub fn update<F>(&mut self, index: LinkedListIndex, f: F) -> bool
+    where
+        F: FnOnce(&mut T),
+    {
+        let Some(mut value) = self.get(index).map(|item| item.value.clone()) else {
+            return false;
+        };
+        f(&mut value);
+        self.replace(index, value).is_some()
+    }
+
+    fn assert_live(&self, index: LinkedListIndex) {
+        assert!(
+            !self.detached.contains_key(index),
+            "index was removed in this transaction"
+        );
+    }
+
+    fn neighbours(
+        &self,
+        index: LinkedListIndex,
+    ) -> (Option<LinkedListIndex>, Option<LinkedListIndex>) {
+        self.list
+            .get(index)
+            .map_or((None, None), |item| (item.prev_index, item.next_index))
+    }
+
+    fn inserted(&mut self, index: LinkedListIndex) {
+        let (prev, next) = self.neighbours(index);
+        let value = self.list.get(index).unwrap().value.clone();
+        self.undo.push(Undo::Insert(index));
+        self.changes.push(Change::Insert {
+            index,
+            prev,
+            next,
+            value,
+        });
+    }
+
+    fn moved(
+        &mut self,
+        index: LinkedListIndex,
+        from: (Option<LinkedListIndex>, Option<LinkedListIndex>),
+    ) {
+        let (prev, next) = self.neighbours(index);
+        self.undo.push(Undo::Move {
+            index,
+            prev: from.0,
+            next: from.1,
+        });
+        self.changes.push(Change::Move { index, prev, next });
+    }
+
+    /// Drop the detached items for good and hand out the changes.
+    fn commit(mut self) -> ChangeSet<T> {
+        self.undo.clear();
+        for (index, ()) in std::mem::take(&mut self.detached) {
+            self.list.remove_detached(index);
+        }
+        ChangeSet {
+            changes: std::mem::take(&mut self.changes),
+        }
+    }
+}
+
+impl<'a, T> Transaction<'a, T> {
+    /// Revert every change, last change first.
+    fn rollback(&mut self) {
+        while let Some(undo) = self.undo.pop() {
+            match undo {
+                Undo::Insert(index) => {
+                    self.list.remove(index);
+                }
+                Undo::Remove { index, prev, next } => {
+                    self.detached.remove(index);
+                    self.list.link(index, prev, next);
+                    self.list.emit(ListEventKind::Insert, index, prev, next);
+                }
+                Undo::Move { index, prev, next } => {
+                    self.list.unlink(index);
+                    self.list.link(index, prev, next);
+                    self.list.emit(ListEventKind::Move, index, prev, next);
+                }
+                Undo::Update { index, value } => {
+                    self.list.replace(index, value);
+                }
+            }
+        }
+        self.changes.clear();
+    }
+}
+
+impl<'a, T> Drop for Transaction<'a, T> {
+    fn drop(&mut self) {
+        self.rollback();
+    }
+}
@@ -0,0 +1,306 @@
+use core::fmt;
+use slotmap::{new_key_type, SecondaryMap, SlotMap};
+
+use crate::walker::{BfsWalker, PostOrderWalker, PreOrderWalker, Walker};
+
+new_key_type! {
+    /// A newtype for the index of a node in an [`ArenaTree`].
+    pub struct TreeIndex;
+}
+
+/// A node in an [`ArenaTree`].
+///
+/// The children of a node form a doubly linked sibling chain, just like the items of a [`LinkedList`](crate::LinkedList).
+#[derive(Debug)]
+pub struct TreeNode<T> {
+    /// The value of the node.
+    pub value: T,
+    parent: Option<TreeIndex>,
+    first_child: Option<TreeIndex>,
+    last_child: Option<TreeIndex>,
+    prev_sibling: Option<TreeIndex>,
+    next_sibling: Option<TreeIndex>,
+}
+
+impl<T> TreeNode<T> {
+    /// The index of the parent, None for roots.
+    pub fn parent(&self) -> Option<TreeIndex> {
+        self.parent
+    }
+
+    /// The index of the first child.
+    pub fn first_child(&self) -> Option<TreeIndex> {
+        self.first_child
+    }
+
+    /// The index of the last child.
+    pub fn last_child(&self) -> Option<TreeIndex> {
+        self.last_child
+    }
+
+    /// The index of the previous sibling.
+    pub fn prev_sibling(&self) -> Option<TreeIndex> {
+        self.prev_sibling
+    }
+
+    /// The index of the next sibling.
+    pub fn next_sibling(&self) -> Option<TreeIndex> {
+        self.next_sibling
+    }
+}
+
+/// A forest of trees stored in a single SlotMap, with parent, first/last child and sibling links.
+///
+/// Every node without a parent is the root of its own tree. Structural changes are O(1), except
+/// [`reparent`](Self::reparent) which walks up from the new parent to rule out cycles, and
+/// [`remove`](Self::remove) which visits the removed subtree.
+///
+/// The traversals are also available as `PreOrderWalker`, `PostOrderWalker` and `BfsWalker`
+/// (behind the `unstable` feature), which don't hold a borrow of the tree so values can be
+/// modified while walking.
+///
+/// # Example
+/// ```
+/// use fast_list::ArenaTree;
+///
+/// let mut tree = ArenaTree::new();
+/// let root = tree.new_node("root");
+/// let a = tree.append_child(root, "a");
+/// let b = tree.append_child(root, "b");
+/// tree.append_child(a, "a1");
+/// tree.insert_sibling_after(a, "a'");
+///
+/// let values = |tree: &ArenaTree<&'static str>, order: Vec<_>| {
+///     order.into_iter().map(|node| tree.get(node).unwrap().value).collect::<Vec<_>>()
+/// };
+/// assert_eq!(values(&tree, tree.pre_order(root).collect()), vec!["root", "a", "a1", "a'", "b"]);
+/// assert_eq!(values(&tree, tree.post_order(root).collect()), vec!["a1", "a", "a'", "b", "root"]);
+/// assert_eq!(values(&tree, tree.bfs(root).collect()), vec!["root", "a", "a'", "b", "a1"]);
+///
+/// tree.reparent(b, a);
+/// assert_eq!(values(&tree, tree.children(a).collect()), vec!["a1", "b"]);
+/// ```
+pub struct ArenaTree<T> {
+    nodes: SlotMap<TreeIndex, TreeNode<T>>,
+}
+
+impl<T: fmt::Debug> fmt::Debug for ArenaTree<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.nodes.values()).finish()
+    }
+}
+
+impl<T> Default for ArenaTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> ArenaTree<T> {
+    /// Create a new empty tree.
+    pub fn new() -> Self {
+        Self {
+            nodes: SlotMap::with_key(),
+        }
+    }
+
+    /// Get the number of nodes in all trees.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns true if there are no nodes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Checks if the tree contains the given index.
+    pub fn contains_key(&self, index: TreeIndex) -> bool {
+        self.nodes.contains_key(index)
+    }
+
+    /// Convenience method to return a slotmap::SecondaryMap of type V
+    pub fn new_data<V>(&self) -> SecondaryMap<TreeIndex, V> {
+        SecondaryMap::new()
+    }
+
+    /// Get a node.
+    #[inline]
+    pub fn get(&self, index: TreeIndex) -> Option<&TreeNode<T>> {
+        self.nodes.get(index)
+    }
+
+    /// Get a mutable reference to a node.
+    #[inline]
+    pub fn get_mut(&mut self, index: TreeIndex) -> Option<&mut TreeNode<T>> {
+        self.nodes.get_mut(index)
+    }
+
+    /// Add a node without a parent (a new root) and return its index.
+    pub fn new_node(&mut self, value: T) -> TreeIndex {
+        self.nodes.insert(TreeNode {
+            value,
+            parent: None,
+            first_child: None,
+            last_child: None,
+            prev_sibling: None,
+            next_sibling: None,
+        })
+    }
+
+    /// Add a node as the last child of `parent` and return its index.
+    ///
+    /// # Panics
+    /// Panics if the parent does not exist.
+    pub fn append_child(&mut self, parent: TreeIndex, value: T) -> TreeIndex {
+        assert!(self.nodes.contains_key(parent), "parent does not exist");
+        let index = self.new_node(value);
+        let last = self.nodes[parent].last_child;
+        self.link(index, parent, last, None);
+        index
+    }
+
+    /// Add a node as the first child of `parent` and return its index.
+    ///
+    /// # Panics
+    /// Panics if the parent does not exist.
+    pub fn prepend_child(&mut self, parent: TreeIndex, value: T) -> TreeIndex {
+        assert!(self.nodes.contains_key(parent), "parent does not exist");
+        let index = self.new_node(value);
+        let first = self.nodes[parent].first_child;
+        self.link(index, parent, None, first);
+        index
+    }
+
+    /// Add a node right after `sibling`, under the same parent, and return its index.
+    ///
+    /// # Panics
+    /// Panics if the sibling does not exist or is a root.
+    pub fn insert_sibling_after(&mut self, sibling: TreeIndex, value: T) -> TreeIndex {
+        let node = &self.nodes[sibling];
+        let parent = node.parent.expect("roots have no siblings");
+        let next = node.next_sibling;
+        let index = self.new_node(value);
+        self.link(index, parent, Some(sibling), next);
+        index
+    }
+
+    /// Add a node right before `sibling`, under the same parent, and return its index.
+    ///
+    /// # Panics
+    /// Panics if the sibling does not exist or is a root.
+    pub fn insert_sibling_before(&mut self, sibling: TreeIndex, value: T) -> TreeIndex {
+        let node = &self.nodes[sibling];
+        let parent = node.parent.expect("roots have no siblings");
+        let prev = node.prev_sibling;
+        let index = self.new_node(value);
+        self.link(index, parent, prev, Some(sibling));
+        index
+    }
+
+    /// Detach a node (and its subtree) from its parent, making it a root.
+    ///
+    /// Returns false if the index does not exist.
+    pub fn detach(&mut self, index: TreeIndex) -> bool {
+        let Some(node) = self.nodes.get_mut(index) else {
+            return false;
+        };
+        let parent = node.parent.take();
+        let prev = node.prev_sibling.take();
+        let next = node.next_sibling.take();
+        let Some(parent) = parent else {
+            return true;
+        };
+
+        match prev {
+            Some(prev) => self.nodes[prev].next_sibling = next,
+            None => self.nodes[parent].first_child = next,
+        }
+        match next {
+            Some(next) => self.nodes[next].prev_sibling = prev,
+            None => self.nodes[parent].last_child = prev,
+        }
+        true
+    }
+
+    /// Move a node (and its subtree) to the end of the children of `parent`.
+    ///
+    /// Returns false, without changing anything, if either index does not exist or if `parent`
+    /// is the node itself or one of its descendants.
+    pub fn reparent(&mut self, index: TreeIndex, parent: TreeIndex) -> bool {
+        if !self.nodes.contains_key(index) || !self.nodes.contains_key(parent) {
+            return false;
+        }
+        if self.ancestors(parent).any(|ancestor| ancestor == index) {
+            return false;
+        }
+        self.detach(index);
+        let last = self.nodes[parent].last_child;
+        self.link(index, parent, last, None);
+        true
+    }
+
+    /// Remove a node and its whole subtree, returning the value of the node.
+    pub fn remove(&mut self, index: TreeIndex) -> Option<T> {
+        if !self.detach(index) {
+            return None;
+        }
+        let descendants = self.pre_order(index).skip(1).collect::<Vec<_>>();
+        for descendant in descendants {
+            self.nodes.remove(descendant);
+        }
+        self.nodes.remove(index).map(|node| node.value)
+    }
+
+    /// Returns an iterator over the children of a node, from the first to the last.
+    pub fn children(&self, index: TreeIndex) -> impl Iterator<Item = TreeIndex> + '_ {
+        let first = self.nodes.get(index).and_then(|node| node.first_child);
+        std::iter::successors(first, move |child| self.nodes[*child].next_sibling)
+    }
+
+    /// Returns an iterator over the node itself, its parent, its grandparent and so on up to the root.
+    pub fn ancestors(&self, index: TreeIndex) -> impl Iterator<Item = TreeIndex> + '_ {
+        let start = Some(index).filter(|index| self.nodes.contains_key(*index));
+        std::iter::successors(start, move |node| self.nodes[*node].parent)
+    }
+
+    /// Returns an iterator over the subtree of `root` in pre-order (parents before their children).
+    pub fn pre_order(&self, root: TreeIndex) -> impl Iterator<Item = TreeIndex> + '_ {
+        PreOrderWalker::new(self, root).iter(self)
+    }
+
+    /// Returns an iterator over the subtree of `root` in post-order (children before their parents).
+    pub fn post_order(&self, root: TreeIndex) -> impl Iterator<Item = TreeIndex> + '_ {
+        PostOrderWalker::new(self, root).iter(self)
+    }
+
+    /// Returns an iterator over the subtree of `root` in breadth-first order.
+    pub fn bfs(&self, root: TreeIndex) -> impl Iterator<Item = TreeIndex> + '_ {
+        BfsWalker::new(self, root).iter(self)
+    }
+
+    /// Link a root node under `parent` in between the adjacent children `prev` and `next`.
+    fn link(
+        &mut self,
+        index: TreeIndex,
+        parent: TreeIndex,
+        prev: Option<TreeIndex>,
+        next: Option<TreeIndex>,
+    ) {
+        let node = &mut self.nodes[index];
+        node.parent = Some(parent);
+        node.prev_sibling = prev;
+        node.next_sibling = next;
+
+        match prev {
+            Some(prev) => self.nodes[prev].next_sibling = Some(index),
+            None => self.nodes[parent].first_child = Some(index),
+        }
+        match next {
+            Some(next) => self.nodes[next].prev_sibling = Some(index),
+            None => self.nodes[parent].last_child = Some(index),
+        }
+    }
+}
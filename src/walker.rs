@@ -1,6 +1,9 @@
-use crate::linked_list::{LinkedList, LinkedListIndex, LinkedListItem};
+use std::collections::VecDeque;
 
-/// Shamelessly stolen/inspired by https://docs.rs/petgraph/0.4.13/src/petgraph/visit/traversal.rs.html#355-370
+use crate::linked_list::{LinkedList, LinkedListIndex};
+use crate::tree::{ArenaTree, TreeIndex};
+
+// Shamelessly stolen/inspired by https://docs.rs/petgraph/0.4.13/src/petgraph/visit/traversal.rs.html#355-370
 
 /// A walker is a traversal state, but where part of the traversal
 /// information is supplied manually to each next call.
@@ -61,7 +64,7 @@ where
     }
 }
 
-impl<'a, C, W: ?Sized> Walker<C> for &'a mut W
+impl<C, W: ?Sized> Walker<C> for &mut W
 where
     W: Walker<C>,
 {
@@ -79,10 +82,10 @@ pub struct LinkedListWalker {
 }
 
 impl LinkedListWalker {
-    pub fn new<T>(list: &LinkedList<T>, start: LinkedListIndex, reverse: bool) -> Self {
+    pub fn new<T>(_list: &LinkedList<T>, start: LinkedListIndex, reverse: bool) -> Self {
         Self {
             current: Some(start),
-            reverse: reverse,
+            reverse,
         }
     }
 }
@@ -112,6 +115,125 @@ impl<T> Walker<&LinkedList<T>> for LinkedListWalker {
     }
 }
 
+/// Walks the subtree of a node of an [`ArenaTree`] in pre-order, parents before their children.
+///
+/// Keeps no stack, the next node is found through the parent and sibling links.
+/// ## Includes the starting node
+pub struct PreOrderWalker {
+    root: TreeIndex,
+    next: Option<TreeIndex>,
+}
+
+impl PreOrderWalker {
+    pub fn new<T>(tree: &ArenaTree<T>, root: TreeIndex) -> Self {
+        Self {
+            root,
+            next: Some(root).filter(|root| tree.contains_key(*root)),
+        }
+    }
+}
+
+impl<T> Walker<&ArenaTree<T>> for PreOrderWalker {
+    type Item = TreeIndex;
+
+    fn walk_next(&mut self, context: &ArenaTree<T>) -> Option<Self::Item> {
+        let current = self.next?;
+        let node = context.get(current)?;
+        self.next = node.first_child().or_else(|| {
+            // Climb up until a node has a next sibling, without leaving the subtree.
+            let mut index = current;
+            loop {
+                if index == self.root {
+                    return None;
+                }
+                let node = context.get(index)?;
+                if let Some(next) = node.next_sibling() {
+                    return Some(next);
+                }
+                index = node.parent()?;
+            }
+        });
+        Some(current)
+    }
+}
+
+/// Walks the subtree of a node of an [`ArenaTree`] in post-order, children before their parents.
+///
+/// Keeps no stack, the next node is found through the parent and sibling links.
+/// ## Includes the starting node
+pub struct PostOrderWalker {
+    root: TreeIndex,
+    next: Option<TreeIndex>,
+}
+
+impl PostOrderWalker {
+    pub fn new<T>(tree: &ArenaTree<T>, root: TreeIndex) -> Self {
+        Self {
+            root,
+            next: Self::first_leaf(tree, root),
+        }
+    }
+
+    fn first_leaf<T>(tree: &ArenaTree<T>, start: TreeIndex) -> Option<TreeIndex> {
+        let mut index = start;
+        loop {
+            match tree.get(index)?.first_child() {
+                Some(child) => index = child,
+                None => return Some(index),
+            }
+        }
+    }
+}
+
+impl<T> Walker<&ArenaTree<T>> for PostOrderWalker {
+    type Item = TreeIndex;
+
+    fn walk_next(&mut self, context: &ArenaTree<T>) -> Option<Self::Item> {
+        let current = self.next?;
+        let node = context.get(current)?;
+        self.next = if current == self.root {
+            None
+        } else if let Some(next) = node.next_sibling() {
+            Self::first_leaf(context, next)
+        } else {
+            node.parent()
+        };
+        Some(current)
+    }
+}
+
+/// Walks the subtree of a node of an [`ArenaTree`] in breadth-first order.
+/// ## Includes the starting node
+pub struct BfsWalker {
+    queue: VecDeque<TreeIndex>,
+}
+
+impl BfsWalker {
+    pub fn new<T>(tree: &ArenaTree<T>, root: TreeIndex) -> Self {
+        Self {
+            queue: Some(root)
+                .filter(|root| tree.contains_key(*root))
+                .into_iter()
+                .collect(),
+        }
+    }
+}
+
+impl<T> Walker<&ArenaTree<T>> for BfsWalker {
+    type Item = TreeIndex;
+
+    fn walk_next(&mut self, context: &ArenaTree<T>) -> Option<Self::Item> {
+        loop {
+            let current = self.queue.pop_front()?;
+            // Skip nodes that were removed since they were queued.
+            if context.contains_key(current) {
+                self.queue.extend(context.children(current));
+                return Some(current);
+            }
+        }
+    }
+}
+
 // impl<G> Walker<G> for Dfs<G::NodeId, G::Map>
 //     where G: IntoNeighbors + Visitable
 // {
@@ -156,4 +278,42 @@ mod tests {
         assert_eq!(list.get(list.head.unwrap()).unwrap().value, 100);
         assert_eq!(list.get(list.tail.unwrap()).unwrap().value, 0);
     }
+
+    #[test]
+    pub fn test_tree_walkers() {
+        let mut tree = ArenaTree::new();
+        let root = tree.new_node(0);
+        let a = tree.append_child(root, 0);
+        tree.append_child(a, 0);
+        tree.append_child(a, 0);
+        tree.append_child(root, 0);
+
+        // Number the nodes in pre-order while walking, the walker doesn't borrow the tree.
+        let mut count = 0;
+        let mut walker = PreOrderWalker::new(&tree, root);
+        while let Some(index) = walker.walk_next(&tree) {
+            count += 1;
+            tree.get_mut(index).unwrap().value = count;
+        }
+        assert_eq!(count, 5);
+
+        let values = |order: Vec<TreeIndex>| {
+            order
+                .into_iter()
+                .map(|index| tree.get(index).unwrap().value)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            values(PostOrderWalker::new(&tree, root).iter(&tree).collect()),
+            vec![3, 4, 2, 5, 1]
+        );
+        assert_eq!(
+            values(BfsWalker::new(&tree, root).iter(&tree).collect()),
+            vec![1, 2, 5, 3, 4]
+        );
+        assert_eq!(
+            values(PreOrderWalker::new(&tree, a).iter(&tree).collect()),
+            vec![2, 3, 4]
+        );
+    }
 }
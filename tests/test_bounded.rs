@@ -0,0 +1,74 @@
+use fast_list::{BoundedList, OverflowPolicy};
+
+fn values(list: &BoundedList<i32>) -> Vec<i32> {
+    list.head
+        .into_iter()
+        .flat_map(|head| list.iter_next(head))
+        .map(|item| item.value)
+        .collect()
+}
+
+#[test]
+fn test_reject_returns_the_value() {
+    let mut list = BoundedList::new(3);
+    for i in 0..3 {
+        list.push_back(i).unwrap();
+    }
+    assert!(list.is_full());
+    assert_eq!(list.push_front(10).unwrap_err().value, 10);
+    let head = list.head.unwrap();
+    let err = list.insert_after(head, 11).unwrap_err();
+    assert_eq!(err.to_string(), "the list is full");
+    assert_eq!(values(&list), vec![0, 1, 2]);
+
+    assert_eq!(list.pop_front(), Some(0));
+    assert_eq!(list.push_front(10).unwrap().1, None);
+    assert_eq!(values(&list), vec![10, 1, 2]);
+
+    let mut empty = BoundedList::new(0).with_policy(OverflowPolicy::EvictBack);
+    assert_eq!(empty.push_back(1).unwrap_err().value, 1);
+}
+
+#[test]
+fn test_evict_front_and_back() {
+    let mut queue = BoundedList::new(3).with_policy(OverflowPolicy::EvictFront);
+    let evicted = (0..6)
+        .filter_map(|i| queue.push_back(i).unwrap().1)
+        .collect::<Vec<_>>();
+    assert_eq!(evicted, vec![0, 1, 2]);
+    assert_eq!(values(&queue), vec![3, 4, 5]);
+
+    let mut stack = BoundedList::new(3).with_policy(OverflowPolicy::EvictBack);
+    let indexes = (0..3)
+        .map(|i| stack.push_back(i).unwrap().0)
+        .collect::<Vec<_>>();
+    // Evicting the anchor itself puts the new value in its place.
+    let (index, evicted) = stack.insert_before(indexes[2], 7).unwrap();
+    assert_eq!(evicted, Some(2));
+    assert_eq!(values(&stack), vec![0, 1, 7]);
+    assert_eq!(stack.tail, Some(index));
+
+    let (_, evicted) = stack.insert_after(indexes[0], 8).unwrap();
+    assert_eq!(evicted, Some(7));
+    assert_eq!(values(&stack), vec![0, 8, 1]);
+}
+
+#[test]
+fn test_callback_policy() {
+    // Evict the smallest value, but never evict a negative one.
+    let policy = OverflowPolicy::Callback(Box::new(|list: &fast_list::LinkedList<i32>| {
+        list.iter()
+            .min_by_key(|item| item.value)
+            .filter(|item| item.value >= 0)
+            .map(|item| item.index)
+    }));
+    let mut list = BoundedList::new(3).with_policy(policy);
+    for i in [5, 2, 9] {
+        list.push_back(i).unwrap();
+    }
+    assert_eq!(list.push_back(4).unwrap().1, Some(2));
+    assert_eq!(list.push_front(-1).unwrap().1, Some(4));
+    assert_eq!(values(&list), vec![-1, 5, 9]);
+    assert_eq!(list.push_back(3).unwrap_err().value, 3);
+    assert_eq!(list.cap(), 3);
+}
@@ -0,0 +1,130 @@
+#![cfg(feature = "hashbrown")]
+
+use std::fs;
+use std::sync::{Arc, Mutex};
+
+use fast_list::{ArcCache, CachePolicy, CacheStats, LfuCache, LruCache};
+
+const TRACE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/cache_trace.txt");
+
+/// Read a trace file with one key per line, skipping blank lines and `#` comments.
+fn read_trace(path: &str) -> Vec<u64> {
+    fs::read_to_string(path)
+        .unwrap()
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.parse().unwrap())
+        .collect()
+}
+
+/// Look up every key of the trace, inserting it on a miss.
+fn replay<C: CachePolicy<u64, u64>>(cache: &mut C, trace: &[u64]) -> CacheStats {
+    for &key in trace {
+        if cache.get(&key).is_none() {
+            cache.put(key, key * 10);
+        }
+        assert!(cache.len() <= cache.cap());
+        assert_eq!(cache.peek(&key), Some(&(key * 10)));
+    }
+    cache.stats()
+}
+
+#[test]
+fn test_replay_trace() {
+    let trace = read_trace(TRACE);
+    let lru = replay(&mut LruCache::new(50), &trace);
+    let lfu = replay(&mut LfuCache::new(50), &trace);
+    let arc = replay(&mut ArcCache::new(50), &trace);
+
+    for stats in [lru, lfu, arc] {
+        assert_eq!(stats.hits + stats.misses, trace.len() as u64);
+    }
+    // The scans push the hot keys out of an LRU cache, but not out of the frequency-aware ones.
+    assert!(lfu.hit_ratio() > lru.hit_ratio());
+    assert!(arc.hit_ratio() > lru.hit_ratio());
+}
+
+#[test]
+fn test_lfu_evicts_least_frequent_then_least_recent() {
+    let evicted = Arc::new(Mutex::new(Vec::new()));
+    let log = evicted.clone();
+    let mut cache =
+        LfuCache::new(3).with_eviction_callback(move |k, _| log.lock().unwrap().push(k));
+    cache.put(1, ());
+    cache.put(2, ());
+    cache.put(3, ());
+    cache.get(&1);
+    cache.get(&3);
+
+    cache.put(4, ());
+    cache.put(5, ());
+    assert_eq!(*evicted.lock().unwrap(), vec![2, 4]);
+    assert_eq!(cache.frequency(&1), Some(2));
+    assert_eq!(cache.frequency(&5), Some(1));
+
+    assert_eq!(cache.remove(&1), Some(()));
+    assert_eq!(cache.pop_lfu(), Some((5, ())));
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.stats(), CacheStats { hits: 2, misses: 0 });
+}
+
+#[test]
+fn test_arc_ghost_hit_adapts_target() {
+    let mut cache = ArcCache::new(2);
+    cache.put(1, 1);
+    cache.put(2, 2);
+    cache.get(&2);
+    cache.put(3, 3);
+    assert!(!cache.contains(&1));
+    assert_eq!(cache.target_recency_size(), 0);
+
+    // 1 is a ghost of the recency list, so using it again grows the recency target.
+    cache.put(1, 10);
+    assert_eq!(cache.target_recency_size(), 1);
+    assert_eq!(cache.get(&1), Some(&10));
+    assert_eq!(cache.len(), 2);
+    assert!(!cache.contains(&2));
+
+    assert_eq!(cache.remove(&1), Some(10));
+    assert!(cache.get(&1).is_none());
+    assert_eq!(cache.stats(), CacheStats { hits: 2, misses: 1 });
+}
+
+#[test]
+fn test_arc_put_after_remove_keeps_live_entries() {
+    let mut cache = ArcCache::new(2);
+    cache.put(1, 1);
+    cache.get(&1);
+    cache.put(2, 2);
+    // The cache is full, so 2 becomes a ghost.
+    cache.put(3, 3);
+    assert!(!cache.contains(&2));
+
+    // There is room again after the removal, so neither new entry evicts 3.
+    cache.remove(&1);
+    cache.put(4, 4);
+    assert_eq!(cache.len(), 2);
+    assert!(cache.contains(&3) && cache.contains(&4));
+
+    cache.remove(&4);
+    cache.put(2, 20);
+    assert_eq!(cache.len(), 2);
+    assert!(cache.contains(&3) && cache.contains(&2));
+}
+
+#[test]
+fn test_caches_look_up_borrowed_keys() {
+    let mut lfu = LfuCache::new(2);
+    lfu.put("a".to_string(), 1);
+    assert_eq!(lfu.get("a"), Some(&1));
+    assert_eq!(lfu.frequency("a"), Some(2));
+    assert!(lfu.contains("a"));
+    assert_eq!(lfu.remove("a"), Some(1));
+
+    let mut arc = ArcCache::new(2);
+    arc.put("a".to_string(), 1);
+    assert_eq!(arc.get("a"), Some(&1));
+    assert_eq!(arc.peek("a"), Some(&1));
+    assert_eq!(arc.remove("a"), Some(1));
+}
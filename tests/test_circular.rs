@@ -0,0 +1,119 @@
+use fast_list::{CircularList, LinkedList, ListEvent, ListEventKind};
+
+fn values(ring: &CircularList<i32>) -> Vec<i32> {
+    match ring.head {
+        Some(head) => ring.iter_from(head).map(|item| item.value).collect(),
+        None => Vec::new(),
+    }
+}
+
+#[test]
+fn test_next_and_prev_wrap() {
+    let mut ring = CircularList::new();
+    let indexes = ring.extend(0..3);
+
+    assert_eq!(ring.next(indexes[2]), Some(indexes[0]));
+    assert_eq!(ring.prev(indexes[0]), Some(indexes[2]));
+    assert_eq!(ring.next(indexes[0]), Some(indexes[1]));
+
+    let single = ring.split_off(indexes[1]);
+    assert_eq!(ring.next(indexes[0]), Some(indexes[0]));
+    assert_eq!(single.len(), 2);
+}
+
+#[test]
+fn test_set_head_and_rotate() {
+    let mut ring = CircularList::from({
+        let mut list = LinkedList::new();
+        list.extend(0..5);
+        list
+    });
+    let indexes = ring
+        .cursor_iter_from(ring.head.unwrap())
+        .collect::<Vec<_>>();
+
+    assert!(ring.set_head(indexes[3]));
+    assert_eq!(values(&ring), vec![3, 4, 0, 1, 2]);
+    assert_eq!(ring.tail, Some(indexes[2]));
+    assert_eq!(
+        ring.iter().map(|item| item.value).collect::<Vec<_>>(),
+        vec![3, 4, 0, 1, 2]
+    );
+
+    ring.rotate_forward(1);
+    assert_eq!(values(&ring), vec![4, 0, 1, 2, 3]);
+    ring.rotate_forward(4);
+    assert_eq!(values(&ring), vec![3, 4, 0, 1, 2]);
+    ring.rotate_backward(8);
+    assert_eq!(values(&ring), vec![0, 1, 2, 3, 4]);
+    ring.rotate_backward(1);
+    assert_eq!(values(&ring), vec![4, 0, 1, 2, 3]);
+
+    ring.pop_front();
+    ring.push_back(5);
+    assert_eq!(values(&ring), vec![0, 1, 2, 3, 5]);
+
+    let mut empty = CircularList::<i32>::new();
+    empty.rotate_forward(3);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_iter_from_visits_each_item_once() {
+    let mut ring = CircularList::new();
+    let indexes = ring.extend(0..4);
+
+    let forward = ring
+        .iter_from(indexes[1])
+        .map(|item| item.value)
+        .collect::<Vec<_>>();
+    assert_eq!(forward, vec![1, 2, 3, 0]);
+    let backward = ring
+        .iter_back_from(indexes[1])
+        .map(|item| item.value)
+        .collect::<Vec<_>>();
+    assert_eq!(backward, vec![1, 0, 3, 2]);
+    assert!(ring
+        .iter_from(indexes[2])
+        .map(|item| item.index)
+        .eq([indexes[2], indexes[3], indexes[0], indexes[1]]));
+
+    ring.remove(indexes[1]);
+    assert_eq!(ring.cursor_iter_from(indexes[1]).count(), 0);
+    assert_eq!(ring.cursor_iter_from(indexes[3]).count(), 3);
+}
+
+#[test]
+fn test_rotation_events_replay() {
+    let mut ring = CircularList::new();
+    let indexes = ring.extend(0..7);
+    ring.enable_events();
+
+    let mut replica = LinkedList::new();
+    let replica_indexes = replica.extend(0..7);
+    let position = |index| indexes.iter().position(|i| *i == index).unwrap();
+
+    for step in [2, 5, 1, 6, 3] {
+        ring.rotate_forward(step);
+        let events: Vec<ListEvent> = ring.drain_events().collect();
+        for event in &events {
+            assert_eq!(event.kind, ListEventKind::Move);
+            let moved = replica_indexes[position(event.index)];
+            match (event.prev, event.next) {
+                (_, Some(next)) => replica.move_before(moved, replica_indexes[position(next)]),
+                (Some(prev), None) => replica.move_after(moved, replica_indexes[position(prev)]),
+                (None, None) => unreachable!(),
+            };
+        }
+        // Only the shorter side of the ring is moved.
+        assert!(events.len() <= 3);
+        assert_eq!(
+            replica.iter().map(|item| item.value).collect::<Vec<_>>(),
+            values(&ring)
+        );
+    }
+
+    // Setting the current head changes nothing, so nothing is logged.
+    assert!(ring.set_head(ring.head.unwrap()));
+    assert_eq!(ring.drain_events().count(), 0);
+}
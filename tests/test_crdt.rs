@@ -0,0 +1,118 @@
+use fast_list::{RgaList, RgaOp};
+
+/// A small xorshift generator so the simulations are random but reproducible.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+#[test]
+fn test_concurrent_inserts_at_same_position() {
+    let mut a = RgaList::new(1);
+    let mut b = RgaList::new(2);
+    let mut c = RgaList::new(3);
+
+    let first = a.insert(0, "x");
+    b.apply(first.clone());
+    c.apply(first);
+
+    let ops = vec![a.insert(1, "a"), b.insert(1, "b"), c.insert(1, "c")];
+    for op in ops.iter().rev() {
+        a.apply(op.clone());
+    }
+    b.apply_all(ops.clone());
+    c.apply(ops[1].clone());
+    c.apply(ops[0].clone());
+
+    // Same counter, so the greatest site comes first.
+    assert_eq!(a.to_vec(), vec!["x", "c", "b", "a"]);
+    assert_eq!(b.to_vec(), a.to_vec());
+    assert_eq!(c.to_vec(), a.to_vec());
+}
+
+#[test]
+fn test_out_of_order_delivery_is_buffered() {
+    let mut a = RgaList::new(1);
+    let mut b = RgaList::new(2);
+
+    let insert_x = a.insert(0, 'x');
+    let insert_y = a.insert(1, 'y');
+    let delete_x = a.delete(0).unwrap();
+
+    b.apply(delete_x.clone());
+    b.apply(insert_y.clone());
+    assert_eq!(b.pending_len(), 2);
+    assert!(b.is_empty());
+
+    b.apply(insert_x.clone());
+    assert_eq!(b.pending_len(), 0);
+    assert_eq!(b.to_vec(), vec!['y']);
+
+    // Duplicates are ignored.
+    b.apply_all(vec![insert_x, insert_y, delete_x]);
+    assert_eq!(b.to_vec(), a.to_vec());
+    assert_eq!(b.len(), 1);
+}
+
+#[test]
+fn test_replicas_converge() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+
+    for _round in 0..20 {
+        let mut replicas: Vec<RgaList<u32>> = (0..4).map(RgaList::new).collect();
+        // Every replica has an inbox of operations that have not been delivered yet.
+        let mut inboxes: Vec<Vec<RgaOp<u32>>> = vec![Vec::new(); replicas.len()];
+
+        for step in 0..200 {
+            let site = rng.below(replicas.len());
+            let replica = &mut replicas[site];
+
+            let op = if replica.is_empty() || rng.below(3) > 0 {
+                let position = rng.below(replica.len() + 1);
+                Some(replica.insert(position, step))
+            } else {
+                let position = rng.below(replica.len());
+                replica.delete(position)
+            };
+
+            if let Some(op) = op {
+                for (other, inbox) in inboxes.iter_mut().enumerate() {
+                    if other != site {
+                        inbox.push(op.clone());
+                    }
+                }
+            }
+
+            // Deliver a random operation from a random inbox, out of order.
+            let target = rng.below(replicas.len());
+            if !inboxes[target].is_empty() {
+                let i = rng.below(inboxes[target].len());
+                let op = inboxes[target].swap_remove(i);
+                replicas[target].apply(op);
+            }
+        }
+
+        for (replica, inbox) in replicas.iter_mut().zip(inboxes.iter_mut()) {
+            while !inbox.is_empty() {
+                let i = rng.below(inbox.len());
+                replica.apply(inbox.swap_remove(i));
+            }
+            assert_eq!(replica.pending_len(), 0);
+        }
+
+        let expected = replicas[0].to_vec();
+        for replica in &replicas[1..] {
+            assert_eq!(replica.to_vec(), expected);
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use fast_list::{apply_patch, diff, LinkedList, ListEdit, PatchError};
+
+fn list_of(s: &str) -> LinkedList<char> {
+    let mut list = LinkedList::new();
+    list.extend(s.chars());
+    list
+}
+
+fn string_of(list: &LinkedList<char>) -> String {
+    if list.is_empty() {
+        return String::new();
+    }
+    list.iter().map(|item| item.value).collect()
+}
+
+fn edit_distance(edits: &[ListEdit<char>]) -> usize {
+    edits
+        .iter()
+        .map(|edit| match edit {
+            ListEdit::Keep(_) => 0,
+            ListEdit::Remove(n) => *n,
+            ListEdit::Insert(_) => 1,
+        })
+        .sum()
+}
+
+#[test]
+fn test_diff_is_minimal() {
+    // The example from Myers' paper has an edit distance of 5.
+    let edits = diff(&list_of("ABCABBA"), &list_of("CBABAC"));
+    assert_eq!(edit_distance(&edits), 5);
+
+    assert_eq!(
+        diff(&list_of("same"), &list_of("same")),
+        vec![ListEdit::Keep(4)]
+    );
+    assert_eq!(diff(&list_of(""), &list_of("")), vec![]);
+    assert_eq!(
+        diff(&list_of(""), &list_of("ab")),
+        vec![ListEdit::Insert('a'), ListEdit::Insert('b')]
+    );
+    assert_eq!(
+        diff(&list_of("ab"), &list_of("")),
+        vec![ListEdit::Remove(2)]
+    );
+}
+
+#[test]
+fn test_patch_roundtrip() {
+    let pairs = [
+        ("ABCABBA", "CBABAC"),
+        ("", "new"),
+        ("old", ""),
+        ("the quick brown fox", "the quack brown box jumps"),
+        ("aaaa", "aa"),
+        ("abc", "xyzabcxyz"),
+    ];
+    for (from, to) in pairs {
+        let mut a = list_of(from);
+        let b = list_of(to);
+        let edits = diff(&a, &b);
+        apply_patch(&mut a, edits).unwrap();
+        assert_eq!(string_of(&a), to);
+        assert_eq!(a.len(), to.len());
+    }
+}
+
+#[test]
+fn test_diff_of_large_different_lists() {
+    // The edit distance is as large as it gets, keeping a snapshot per edit took about 1 GB here.
+    let mut a = LinkedList::new();
+    a.extend(0..4_000u32);
+    let mut b = LinkedList::new();
+    b.extend(4_000..8_000u32);
+
+    let edits = diff(&a, &b);
+    assert_eq!(edits[0], ListEdit::Remove(4_000));
+    assert_eq!(edits.len(), 4_001);
+    apply_patch(&mut a, edits).unwrap();
+    assert!(a.iter().map(|item| item.value).eq(4_000..8_000));
+}
+
+#[test]
+fn test_patch_keeps_indexes_of_unchanged_items() {
+    let mut a = list_of("abcdef");
+    let kept: Vec<_> = ["b", "d", "f"]
+        .iter()
+        .map(|c| {
+            a.cursor_iter_next(a.head.unwrap())
+                .find(|index| a.get(*index).unwrap().value.to_string() == *c)
+                .unwrap()
+        })
+        .collect();
+
+    apply_patch(&mut a, diff(&list_of("abcdef"), &list_of("xbdyf"))).unwrap();
+
+    assert_eq!(string_of(&a), "xbdyf");
+    assert_eq!(a.get(kept[0]).unwrap().value, 'b');
+    assert_eq!(a.get(kept[1]).unwrap().value, 'd');
+    assert_eq!(a.tail, Some(kept[2]));
+}
+
+#[test]
+fn test_patch_out_of_bounds() {
+    let mut a = list_of("ab");
+    let result = apply_patch(&mut a, vec![ListEdit::Remove(1), ListEdit::Keep(2)]);
+    assert_eq!(result, Err(PatchError::OutOfBounds { needed: 3, len: 2 }));
+    assert_eq!(string_of(&a), "ab");
+}
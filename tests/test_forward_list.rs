@@ -0,0 +1,71 @@
+use fast_list::{ForwardList, ForwardListIndex, ForwardListItem, LinkedListItem};
+
+fn values(list: &ForwardList<i32>) -> Vec<i32> {
+    list.iter().map(|item| item.value).collect()
+}
+
+#[test]
+fn test_push_and_pop() {
+    let mut list = ForwardList::new();
+    assert_eq!(list.pop_front(), None);
+    assert_eq!(values(&list), Vec::<i32>::new());
+
+    list.push_back(2);
+    list.push_front(1);
+    list.push_back(3);
+    assert_eq!(values(&list), vec![1, 2, 3]);
+    assert_eq!(list.tail().unwrap().value, 3);
+
+    assert_eq!(list.pop_front(), Some(1));
+    assert_eq!(list.pop_front(), Some(2));
+    assert_eq!(list.pop_front(), Some(3));
+    assert!(list.head.is_none() && list.tail.is_none());
+
+    list.push_front(4);
+    assert_eq!(list.tail().unwrap().value, 4);
+}
+
+#[test]
+fn test_insert_and_remove_after() {
+    let mut list = ForwardList::new();
+    let indexes = list.extend(0..3);
+
+    let new_tail = list.insert_after(indexes[2], 3);
+    assert_eq!(list.tail, Some(new_tail));
+    list.insert_after(indexes[0], 10);
+    assert_eq!(values(&list), vec![0, 10, 1, 2, 3]);
+
+    assert_eq!(list.remove_after(indexes[0]), Some(10));
+    assert_eq!(list.remove_after(indexes[2]), Some(3));
+    assert_eq!(list.tail, Some(indexes[2]));
+    assert_eq!(list.remove_after(indexes[2]), None);
+    assert_eq!(values(&list), vec![0, 1, 2]);
+    assert_eq!(list.next_of(indexes[0]).unwrap().value, 1);
+    assert_eq!(list.cursor_iter_next(indexes[1]).count(), 2);
+}
+
+#[test]
+fn test_item_is_smaller_than_linked_list_item() {
+    // One link per item instead of two: 16 bytes instead of 24 for u64 values.
+    let link = std::mem::size_of::<Option<ForwardListIndex>>();
+    assert_eq!(
+        std::mem::size_of::<ForwardListItem<u64>>(),
+        std::mem::size_of::<u64>() + link
+    );
+    assert_eq!(
+        std::mem::size_of::<LinkedListItem<u64>>() - std::mem::size_of::<ForwardListItem<u64>>(),
+        link
+    );
+}
+
+#[test]
+fn test_iter_yields_indexes() {
+    let mut list = ForwardList::new();
+    let indexes = list.extend(0..3);
+    assert!(list
+        .iter()
+        .map(|item| item.index)
+        .eq(indexes.iter().copied()));
+    let (index, value) = list.iter_next(indexes[1]).next().unwrap().into();
+    assert_eq!((index, *value), (indexes[1], 1));
+}
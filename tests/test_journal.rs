@@ -0,0 +1,161 @@
+use fast_list::{JournaledList, LinkedList};
+
+fn values(list: &LinkedList<i32>) -> Vec<i32> {
+    if list.is_empty() {
+        return Vec::new();
+    }
+    list.iter().map(|item| item.value).collect()
+}
+
+#[test]
+fn test_undo_redo_groups() {
+    let mut list = JournaledList::new();
+    let a = list.push_back(1);
+    let b = list.push_back(2);
+    list.checkpoint();
+
+    list.insert_after(a, 3);
+    list.push_front(0);
+    list.checkpoint();
+
+    list.remove(b);
+    list.pop_front();
+    assert_eq!(values(&list), vec![1, 3]);
+
+    assert!(list.undo());
+    assert_eq!(values(&list), vec![0, 1, 3, 2]);
+    assert!(list.undo());
+    assert_eq!(values(&list), vec![1, 2]);
+    assert!(list.undo());
+    assert_eq!(values(&list), vec![]);
+    assert!(!list.undo());
+
+    assert!(list.redo());
+    assert!(list.redo());
+    assert!(list.redo());
+    assert!(!list.redo());
+    assert_eq!(values(&list), vec![1, 3]);
+}
+
+#[test]
+fn test_restored_items_resolve() {
+    let mut list = JournaledList::new();
+    let a = list.push_back(1);
+    let b = list.push_back(2);
+    list.checkpoint();
+
+    list.remove(a);
+    list.undo();
+
+    // The item got a new slot, but the old index still works through the journal.
+    assert!(!list.contains_key(a));
+    let restored = list.resolve(a).unwrap();
+    assert_eq!(list.get(restored).unwrap().value, 1);
+    assert_eq!(list.head, Some(restored));
+    assert_eq!(list.resolve(b), Some(b));
+
+    list.insert_after(a, 5);
+    list.checkpoint();
+    list.remove(a);
+    list.undo();
+    assert_eq!(values(&list), vec![1, 5, 2]);
+    assert_eq!(list.resolve(a), list.head);
+
+    list.undo();
+    list.redo();
+    assert_eq!(values(&list), vec![1, 5, 2]);
+
+    // Without history the old index is forgotten, the current one keeps working.
+    let current = list.resolve(a).unwrap();
+    list.clear_history();
+    assert_eq!(list.resolve(a), None);
+    assert_eq!(list.resolve(current), Some(current));
+    assert!(!list.can_undo());
+}
+
+#[test]
+fn test_move_and_replace() {
+    let mut list = JournaledList::from({
+        let mut list = LinkedList::new();
+        list.extend(0..4);
+        list
+    });
+    let first = list.head.unwrap();
+    let last = list.tail.unwrap();
+
+    assert!(list.move_after(first, last));
+    assert_eq!(list.replace(last, 30), Some(3));
+    assert!(list.update(first, |value| *value += 10));
+    assert_eq!(values(&list), vec![1, 2, 30, 10]);
+
+    list.undo();
+    assert_eq!(values(&list), vec![0, 1, 2, 3]);
+    assert_eq!(list.head, Some(first));
+    assert_eq!(list.tail, Some(last));
+
+    list.redo();
+    assert_eq!(values(&list), vec![1, 2, 30, 10]);
+    assert_eq!(list.tail, Some(first));
+}
+
+#[test]
+fn test_new_edit_clears_redo() {
+    let mut list = JournaledList::new();
+    list.push_back(1);
+    list.checkpoint();
+    list.push_back(2);
+    list.undo();
+    assert!(list.can_redo());
+
+    list.push_back(3);
+    assert!(!list.can_redo());
+    assert_eq!(values(&list), vec![1, 3]);
+    assert_eq!(list.into_inner().len(), 2);
+}
+
+#[test]
+fn test_update_is_logged_as_event() {
+    let mut inner = LinkedList::new();
+    inner.enable_events();
+    let mut list = JournaledList::from(inner);
+
+    let a = list.push_back(1);
+    assert!(list.update(a, |value| *value += 1));
+    list.replace(a, 5);
+
+    let mut inner = list.into_inner();
+    let kinds = inner
+        .drain_events()
+        .map(|event| event.kind)
+        .collect::<Vec<_>>();
+    use fast_list::ListEventKind::*;
+    assert_eq!(kinds, vec![Insert, Update, Update]);
+}
+
+#[test]
+fn test_aliases_survive_many_undo_redo_cycles() {
+    let mut list = JournaledList::from({
+        let mut list = LinkedList::new();
+        list.extend(0..3);
+        list
+    });
+    let a = list.head.unwrap();
+    list.remove(a);
+    list.checkpoint();
+
+    for _ in 0..1000 {
+        assert!(list.undo());
+        assert_eq!(values(&list), vec![0, 1, 2]);
+        assert_eq!(list.get(list.resolve(a).unwrap()).unwrap().value, 0);
+        assert!(list.redo());
+        assert_eq!(values(&list), vec![1, 2]);
+    }
+
+    // Once the removal is dropped from the history by a new edit, nothing refers to the old index.
+    list.undo();
+    let current = list.resolve(a).unwrap();
+    list.push_back(3);
+    assert_eq!(list.resolve(a), None);
+    assert_eq!(list.resolve(current), Some(current));
+    assert_eq!(values(&list), vec![0, 1, 2, 3]);
+}
@@ -0,0 +1,122 @@
+#![cfg(feature = "hashbrown")]
+
+use fast_list::{Entry, LinkedHashMap, LinkedHashSet};
+
+#[test]
+fn test_insertion_order_and_reordering() {
+    let mut map = LinkedHashMap::new();
+    for (i, key) in ["a", "b", "c", "d"].iter().enumerate() {
+        assert_eq!(map.insert(*key, i), None);
+    }
+    // Updating a value keeps its position.
+    assert_eq!(map.insert("b", 10), Some(1));
+    assert!(map.move_to_back("a"));
+    assert!(map.move_to_front("d"));
+    assert!(!map.move_to_back("missing"));
+
+    assert_eq!(
+        map.keys().copied().collect::<Vec<_>>(),
+        vec!["d", "b", "c", "a"]
+    );
+    assert_eq!(
+        map.values().rev().copied().collect::<Vec<_>>(),
+        vec![0, 2, 10, 3]
+    );
+    assert_eq!(map.front(), Some((&"d", &3)));
+    assert_eq!(map.back(), Some((&"a", &0)));
+
+    assert_eq!(map.remove("c"), Some(2));
+    assert_eq!(map.pop_back(), Some(("a", 0)));
+    assert_eq!(map.pop_front(), Some(("d", 3)));
+    assert_eq!(map.len(), 1);
+    assert!(map.contains_key("b"));
+}
+
+#[test]
+fn test_stable_indexes() {
+    let mut map: LinkedHashMap<String, u32> = (0..5).map(|i| (i.to_string(), i)).collect();
+    let index = map.get_index("2").unwrap();
+
+    map.remove("1");
+    map.move_to_back("2");
+    map.insert("9".to_string(), 9);
+
+    assert_eq!(map.get_index("2"), Some(index));
+    assert_eq!(map.get_by_index(index), Some((&"2".to_string(), &2)));
+    *map.get_by_index_mut(index).unwrap() += 1;
+    assert_eq!(map.get("2"), Some(&3));
+    assert_eq!(map.indexes().nth(3), Some(index));
+
+    map.remove("2");
+    assert_eq!(map.get_by_index(index), None);
+}
+
+#[test]
+fn test_clear_invalidates_indexes() {
+    let mut map = LinkedHashMap::new();
+    map.insert("a", 1);
+    let index = map.get_index(&"a").unwrap();
+
+    map.clear();
+    assert!(map.is_empty());
+    map.insert("z", 26);
+    assert_eq!(map.get_by_index(index), None);
+    assert_eq!(
+        map.get_by_index(map.get_index(&"z").unwrap()),
+        Some((&"z", &26))
+    );
+}
+
+#[test]
+fn test_entry() {
+    let mut map = LinkedHashMap::new();
+    for word in "the cat saw the other cat".split(' ') {
+        *map.entry(word).or_insert(0) += 1;
+    }
+    assert_eq!(
+        map.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>(),
+        vec![("the", 2), ("cat", 2), ("saw", 1), ("other", 1)]
+    );
+
+    map.entry("saw").and_modify(|v| *v = 5).or_insert(0);
+    assert_eq!(map.get("saw"), Some(&5));
+
+    match map.entry("cat") {
+        Entry::Occupied(mut entry) => {
+            entry.move_to_back();
+            assert_eq!(entry.remove(), 2);
+        }
+        Entry::Vacant(_) => unreachable!(),
+    }
+    match map.entry("dog") {
+        Entry::Occupied(_) => unreachable!(),
+        Entry::Vacant(entry) => {
+            assert_eq!(entry.key(), &"dog");
+            *entry.insert(1) += 1;
+        }
+    }
+    assert_eq!(map.back(), Some((&"dog", &2)));
+    assert_eq!(*map.entry("new").or_insert_with(|| 7), 7);
+    assert_eq!(map.len(), 5);
+}
+
+#[test]
+fn test_set() {
+    let mut set: LinkedHashSet<u32> = [5, 3, 5, 1].into_iter().collect();
+    assert_eq!(set.len(), 3);
+    assert!(set.insert(7));
+    assert!(!set.insert(3));
+    assert!(set.move_to_front(&7));
+    assert!(set.remove(&5));
+    assert!(!set.contains(&5));
+
+    let index = set.get_index(&1).unwrap();
+    assert_eq!(set.get_by_index(index), Some(&1));
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), vec![7, 3, 1]);
+    assert_eq!(set.front(), Some(&7));
+    assert_eq!(set.back(), Some(&1));
+    assert_eq!(set.pop_front(), Some(7));
+    assert_eq!(set.pop_back(), Some(1));
+    set.clear();
+    assert!(set.is_empty());
+}
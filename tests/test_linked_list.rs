@@ -1,14 +1,11 @@
 use std::{
-    sync::{
-        atomic::{AtomicI32, Ordering},
-        Arc, Mutex,
-    },
+    sync::{Arc, Mutex},
     thread,
 };
 
 //use crate::LinkedListCell;
 
-use fast_list::{LinkedList, LinkedListIndex};
+use fast_list::{LinkedList, LinkedListIndex, LinkedListItem, ListEvent, ListEventKind};
 
 #[test]
 fn test_fn_push_back_fn_next_of_fn_prev_of() {
@@ -143,11 +140,11 @@ fn test_fn_insert_after_fn_insert_before() {
     // a -> b -> c
     let mut list = LinkedList::new();
 
-    let (a, b, c, d) = {
+    let (a, b, _c, d) = {
         let a = list.push_back(1);
         let b = list.push_back(2);
         let c = list.push_back(3);
-        let d = list.insert_after(a.clone(), 4);
+        let d = list.insert_after(a, 4);
 
         // a -> d -> b -> c
         (a, b, c, d)
@@ -167,7 +164,7 @@ fn test_fn_insert_after_fn_insert_before() {
 fn test_iter() {
     let mut list = LinkedList::new();
     let verticies: Vec<LinkedListIndex> = (0..100)
-        .map(|i| list.push_back(format!("Node: {}", i.to_string())))
+        .map(|i| list.push_back(format!("Node: {}", i)))
         .collect();
 
     for n in list.iter_next(verticies[0]) {
@@ -183,7 +180,7 @@ fn test_iter() {
 fn test_popback() {
     let mut list = LinkedList::new();
     let _verticies: Vec<LinkedListIndex> = (0..100)
-        .map(|i| list.push_back(format!("Node: {}", i.to_string())))
+        .map(|i| list.push_back(format!("Node: {}", i)))
         .collect();
 
     let mut i = 99;
@@ -191,10 +188,393 @@ fn test_popback() {
         i -= 1;
 
         //println!("Popped: {:?}", popped);
-        let expected = format!("Node: {}", (i).to_string());
+        let expected = format!("Node: {}", i);
         if i >= 0 {
             let last = list.tail.unwrap();
             assert_eq!(list.get(last).unwrap().value, expected);
         }
     }
 }
+
+#[test]
+fn test_split_off_moves_rest_of_list() {
+    let mut list = LinkedList::new();
+    let indexes = list.extend(0..5);
+
+    let split = list.split_off(indexes[2]);
+
+    assert_eq!(
+        list.iter().map(|item| item.value).collect::<Vec<_>>(),
+        vec![0, 1]
+    );
+    assert_eq!(list.tail, Some(indexes[1]));
+    assert_eq!(
+        split.iter().map(|item| item.value).collect::<Vec<_>>(),
+        vec![2, 3, 4]
+    );
+}
+
+#[test]
+fn test_events() {
+    let mut list = LinkedList::new();
+    list.push_back(0);
+    assert_eq!(list.drain_events().count(), 0);
+
+    list.enable_events();
+    let indexes = list.extend(1..5);
+    let head = list.head.unwrap();
+    list.move_after(head, indexes[3]);
+    list.replace(indexes[0], 10);
+    list.retain_mut(|value| *value % 2 == 0);
+    let split = list.split_off(indexes[3]);
+
+    let events: Vec<ListEvent> = list.drain_events().collect();
+    let kinds: Vec<ListEventKind> = events.iter().map(|event| event.kind).collect();
+    use ListEventKind::*;
+    assert_eq!(
+        kinds,
+        vec![Insert, Insert, Insert, Insert, Move, Update, Remove, Remove, Remove]
+    );
+    assert_eq!(
+        events[4],
+        ListEvent {
+            kind: Move,
+            index: head,
+            prev: Some(indexes[3]),
+            next: None
+        }
+    );
+    // retain_mut removed 3 and then split_off took 4 and the old head.
+    assert_eq!(events[6].index, indexes[2]);
+    assert_eq!(events[7].index, indexes[3]);
+    assert_eq!(events[8].index, head);
+    assert_eq!(events[8].prev, Some(indexes[1]));
+    assert_eq!(split.len(), 2);
+    assert!(!split.events_enabled());
+
+    assert_eq!(list.drain_events().count(), 0);
+    list.disable_events();
+    list.push_back(5);
+    assert_eq!(list.drain_events().count(), 0);
+}
+
+#[test]
+fn test_events_of_rolled_back_transaction() {
+    let mut list = LinkedList::new();
+    let indexes = list.extend(0..3);
+    list.enable_events();
+
+    let _ = list.transaction(|tx| {
+        tx.remove(indexes[1]);
+        Err::<(), ()>(())
+    });
+
+    let events: Vec<ListEvent> = list.drain_events().collect();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].kind, ListEventKind::Remove);
+    assert_eq!(events[1].kind, ListEventKind::Insert);
+    assert_eq!(events[1].index, indexes[1]);
+    assert_eq!(events[1].prev, Some(indexes[0]));
+    assert_eq!(events[1].next, Some(indexes[2]));
+}
+
+fn values(list: &LinkedList<i32>) -> Vec<i32> {
+    list.head
+        .into_iter()
+        .flat_map(|head| list.iter_next(head))
+        .map(|item| item.value)
+        .collect()
+}
+
+#[test]
+fn test_retain_and_extract_if() {
+    let mut list = LinkedList::new();
+    let indexes = list.extend(0..10);
+
+    list.retain(|value| {
+        *value += 1;
+        *value % 3 != 0
+    });
+    assert_eq!(values(&list), vec![1, 2, 4, 5, 7, 8, 10]);
+
+    let extracted = list
+        .extract_if(|_, value| *value % 2 == 0)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        extracted,
+        vec![
+            (indexes[1], 2),
+            (indexes[3], 4),
+            (indexes[7], 8),
+            (indexes[9], 10)
+        ]
+    );
+    assert_eq!(values(&list), vec![1, 5, 7]);
+    assert_eq!(list.tail, Some(indexes[6]));
+
+    // Dropping the iterator early keeps the items it did not reach.
+    let first = list.extract_if(|_, _| true).next();
+    assert_eq!(first, Some((indexes[0], 1)));
+    assert_eq!(values(&list), vec![5, 7]);
+
+    list.retain_with_index(|list, index| list.next_of(index).is_none());
+    assert_eq!(values(&list), vec![7]);
+}
+
+#[test]
+fn test_retain_is_panic_safe() {
+    let mut list = LinkedList::new();
+    list.extend(0..6);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        list.retain(|value| {
+            assert!(*value < 3);
+            *value % 2 == 0
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(values(&list), vec![0, 2, 3, 4, 5]);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        list.retain_with_index(|list, index| {
+            assert!(list.get(index).unwrap().value < 4);
+            false
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(values(&list), vec![0, 2, 3, 4, 5]);
+    assert_eq!(list.len(), 5);
+}
+
+#[test]
+fn test_drain() {
+    let mut list = LinkedList::new();
+    list.extend(0..5);
+    list.enable_events();
+
+    let mut drain = list.drain();
+    assert_eq!(drain.len(), 5);
+    assert_eq!(drain.next(), Some(0));
+    assert_eq!(drain.next_back(), Some(4));
+    drop(drain);
+
+    assert!(list.is_empty());
+    assert_eq!((list.head, list.tail), (None, None));
+    assert_eq!(list.drain_events().count(), 5);
+    assert_eq!(list.drain().count(), 0);
+}
+
+#[test]
+fn test_dedup() {
+    let mut list = LinkedList::new();
+    let indexes = list.extend([1, 1, 2, 3, 3, 3, 1, 4, 4]);
+    let removed = list.dedup();
+    assert_eq!(
+        removed,
+        vec![indexes[1], indexes[4], indexes[5], indexes[8]]
+    );
+    assert_eq!(values(&list), vec![1, 2, 3, 1, 4]);
+    assert_eq!(list.tail, Some(indexes[7]));
+    assert!(list.dedup().is_empty());
+
+    let mut list = LinkedList::new();
+    list.extend([10, 11, 25, 29, 21, 30]);
+    list.dedup_by_key(|value| *value / 10);
+    assert_eq!(values(&list), vec![10, 25, 30]);
+
+    // dedup_by gets the candidate first, like Vec::dedup_by.
+    let mut list = LinkedList::new();
+    list.extend([1, 2, 4, 3, 8]);
+    list.dedup_by(|candidate, survivor| candidate > survivor);
+    assert_eq!(values(&list), vec![1]);
+
+    let mut empty = LinkedList::<i32>::new();
+    assert!(empty.dedup().is_empty());
+}
+
+#[test]
+fn test_coalesce_merges_runs() {
+    let mut list = LinkedList::new();
+    let indexes = list.extend([("a", 1), ("a", 2), ("b", 1), ("b", 1), ("b", 5), ("a", 1)]);
+    list.enable_events();
+
+    let removed = list.coalesce(|keep, drop| {
+        if keep.0 != drop.0 {
+            return false;
+        }
+        keep.1 += drop.1;
+        true
+    });
+    assert_eq!(removed, vec![indexes[1], indexes[3], indexes[4]]);
+    let merged = list.iter().map(|item| item.value).collect::<Vec<_>>();
+    assert_eq!(merged, vec![("a", 3), ("b", 7), ("a", 1)]);
+    assert_eq!(list.get(indexes[2]).unwrap().value, ("b", 7));
+    assert_eq!(list.drain_events().count(), 3);
+}
+
+#[test]
+fn test_reverse() {
+    let mut list = LinkedList::new();
+    let indexes = list.extend(0..5);
+    list.enable_events();
+    list.reverse();
+
+    assert_eq!(values(&list), vec![4, 3, 2, 1, 0]);
+    assert_eq!((list.head, list.tail), (Some(indexes[4]), Some(indexes[0])));
+    assert_eq!(list.cursor_next(indexes[2]), Some(indexes[1]));
+    assert_eq!(list.cursor_prev(indexes[2]), Some(indexes[3]));
+    assert_eq!(list.next_of_mut(indexes[2]).unwrap().value, 1);
+    assert_eq!(list.prev_of_mut(indexes[2]).unwrap().value, 3);
+    assert!(list.next_of_mut(indexes[0]).is_none());
+    assert_eq!(list.nth(1), Some(indexes[3]));
+
+    // Replaying the moves on a copy of the original order reverses it too.
+    let mut replica = LinkedList::new();
+    let replica_indexes = replica.extend(0..5);
+    for event in list.drain_events() {
+        assert_eq!(event.kind, ListEventKind::Move);
+        let position = |index| indexes.iter().position(|i| *i == index).unwrap();
+        let next = replica_indexes[position(event.next.unwrap())];
+        replica.move_before(replica_indexes[position(event.index)], next);
+    }
+    assert_eq!(values(&replica), vec![4, 3, 2, 1, 0]);
+
+    let mut single = LinkedList::new();
+    single.push_back(1);
+    single.reverse();
+    assert_eq!(values(&single), vec![1]);
+}
+
+#[test]
+fn test_reversed_view() {
+    let mut list = LinkedList::new();
+    let indexes = list.extend(0..6);
+    let view = list.reversed();
+
+    let forward = view.iter().map(|item| item.value).collect::<Vec<_>>();
+    assert_eq!(forward, vec![5, 4, 3, 2, 1, 0]);
+    let from_three = view.iter_next(indexes[3]).map(|item| item.value);
+    assert_eq!(from_three.collect::<Vec<_>>(), vec![3, 2, 1, 0]);
+    assert_eq!(
+        view.cursor_iter_prev(indexes[3]).collect::<Vec<_>>(),
+        indexes[3..].to_vec()
+    );
+    assert_eq!(view.head().unwrap().value, 5);
+    assert_eq!(view.next_of(indexes[5]).unwrap().value, 4);
+    assert_eq!(view.prev_of(indexes[5]).map(|item| item.value), None);
+    assert_eq!(view.cursor_prev(indexes[0]), Some(indexes[1]));
+    assert_eq!(
+        (0..7).map(|n| view.nth(n)).collect::<Vec<_>>(),
+        indexes
+            .iter()
+            .rev()
+            .map(|index| Some(*index))
+            .chain([None])
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(values(view.list()), vec![0, 1, 2, 3, 4, 5]);
+
+    let empty = LinkedList::<i32>::new();
+    assert_eq!(empty.reversed().iter().count(), 0);
+}
+
+#[test]
+fn test_splice() {
+    let mut list = LinkedList::new();
+    let indexes = list.extend([0, 10]);
+    list.enable_events();
+
+    let middle = list.splice_after(indexes[0], 1..4);
+    assert_eq!(values(&list), vec![0, 1, 2, 3, 10]);
+    let front = list.splice_before(indexes[0], [-2, -1]);
+    let back = list.splice_after(indexes[1], [11, 12]);
+    assert_eq!(values(&list), vec![-2, -1, 0, 1, 2, 3, 10, 11, 12]);
+    assert_eq!(list.head, Some(front[0]));
+    assert_eq!(list.tail, Some(back[1]));
+    assert_eq!(list.cursor_prev(indexes[1]), Some(middle[2]));
+    assert!(list.splice_before(indexes[1], []).is_empty());
+    assert_eq!(list.len(), 9);
+
+    let events = list.drain_events().collect::<Vec<_>>();
+    assert_eq!(events.len(), 7);
+    assert!(events
+        .iter()
+        .all(|event| event.kind == ListEventKind::Insert));
+    assert_eq!(events[1].prev, Some(middle[0]));
+    assert_eq!(events[1].next, Some(indexes[1]));
+    assert_eq!(events[3].prev, None);
+    assert_eq!(events[4].next, Some(indexes[0]));
+
+    let mut empty = LinkedList::new();
+    let head = empty.push_back(0);
+    empty.splice_before(head, [1]);
+    empty.splice_after(head, [2]);
+    assert_eq!(values(&empty), vec![1, 0, 2]);
+}
+
+#[test]
+fn test_splice_list_after() {
+    let mut list = LinkedList::new();
+    let indexes = list.extend([1, 5]);
+    let mut other = LinkedList::new();
+    let moved = other.extend([2, 3, 4]);
+
+    let remap = list.splice_list_after(indexes[0], other);
+    assert_eq!(values(&list), vec![1, 2, 3, 4, 5]);
+    assert_eq!(remap.len(), 3);
+    for (old, value) in moved.into_iter().zip([2, 3, 4]) {
+        assert_eq!(list.get(remap[old]).unwrap().value, value);
+    }
+    assert_eq!(list.tail, Some(indexes[1]));
+
+    let remap = list.splice_list_after(indexes[1], LinkedList::new());
+    assert!(remap.is_empty());
+    assert_eq!(list.tail, Some(indexes[1]));
+}
+
+#[test]
+fn test_entry() {
+    let mut list = LinkedList::new();
+    let indexes = list.extend([1, 2, 3]);
+    assert!(list.entry(LinkedListIndex::default()).is_none());
+    list.enable_events();
+
+    let mut entry = list.entry(indexes[1]).unwrap();
+    assert_eq!(entry.index(), indexes[1]);
+    assert_eq!(*entry.value(), 2);
+    *entry.value_mut() += 10;
+    assert_eq!(entry.replace(20), 12);
+    let before = entry.insert_before(15);
+    let after = entry.insert_after(25);
+    assert_eq!(entry.prev_index(), Some(before));
+    assert_eq!(entry.next_index(), Some(after));
+
+    let entry = entry.next().unwrap().next().unwrap();
+    assert_eq!(entry.index(), indexes[2]);
+    assert!(entry.next().is_none());
+
+    let head = list.entry(indexes[0]).unwrap();
+    assert!(head.prev().is_none());
+    *list.entry(indexes[0]).unwrap().into_value_mut() = 0;
+
+    let entry = list.entry(before).unwrap().prev().unwrap();
+    assert_eq!(entry.remove(), 0);
+    assert_eq!(values(&list), vec![15, 20, 25, 3]);
+    assert_eq!(list.head, Some(before));
+
+    let kinds = list
+        .drain_events()
+        .map(|event| event.kind)
+        .collect::<Vec<_>>();
+    use ListEventKind::*;
+    assert_eq!(kinds, vec![Update, Insert, Insert, Remove]);
+}
+
+#[test]
+fn test_item_does_not_store_its_own_index() {
+    // Items used to store their own index as well, which took another 8 bytes per item.
+    assert_eq!(
+        std::mem::size_of::<LinkedListItem<u64>>(),
+        std::mem::size_of::<u64>() + 2 * std::mem::size_of::<Option<LinkedListIndex>>()
+    );
+}
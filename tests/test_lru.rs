@@ -0,0 +1,79 @@
+#![cfg(feature = "hashbrown")]
+
+use std::sync::{Arc, Mutex};
+
+use fast_list::LruCache;
+
+#[test]
+fn test_get_promotes_and_peek_does_not() {
+    let mut cache = LruCache::new(3);
+    cache.put(1, "one");
+    cache.put(2, "two");
+    cache.put(3, "three");
+
+    assert_eq!(cache.peek(&1), Some(&"one"));
+    assert_eq!(cache.peek_lru(), Some((&1, &"one")));
+
+    assert_eq!(cache.get(&1), Some(&"one"));
+    assert_eq!(cache.peek_lru(), Some((&2, &"two")));
+    assert_eq!(
+        cache.iter().map(|(k, _)| *k).collect::<Vec<_>>(),
+        vec![1, 3, 2]
+    );
+
+    cache.put(4, "four");
+    assert!(!cache.contains(&2));
+    assert_eq!(cache.len(), 3);
+}
+
+#[test]
+fn test_put_existing_key_updates_value() {
+    let mut cache = LruCache::new(2);
+    cache.put("a", 1);
+    cache.put("b", 2);
+    assert_eq!(cache.put("a", 10), Some(1));
+    assert_eq!(cache.len(), 2);
+    *cache.get_mut("b").unwrap() += 1;
+
+    assert_eq!(cache.pop_lru(), Some(("a", 10)));
+    assert_eq!(cache.pop("b"), Some(3));
+    assert!(cache.is_empty());
+    assert_eq!(cache.pop_lru(), None);
+}
+
+#[test]
+fn test_eviction_callback_and_resize() {
+    let evicted = Arc::new(Mutex::new(Vec::new()));
+    let mut cache = LruCache::new(3).with_eviction_callback({
+        let evicted = Arc::clone(&evicted);
+        move |key: String, value: u32| evicted.lock().unwrap().push((key, value))
+    });
+
+    for i in 0..5 {
+        cache.put(i.to_string(), i);
+    }
+    assert_eq!(
+        *evicted.lock().unwrap(),
+        vec![("0".to_string(), 0), ("1".to_string(), 1)]
+    );
+
+    cache.get("2");
+    cache.resize(1);
+    assert_eq!(cache.cap(), 1);
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.peek("2"), Some(&2));
+    assert_eq!(evicted.lock().unwrap().len(), 4);
+
+    // Explicit removals are not evictions.
+    cache.pop_lru();
+    cache.put("x".to_string(), 9);
+    cache.clear();
+    assert!(cache.is_empty());
+    assert_eq!(cache.peek("x"), None);
+    assert_eq!(evicted.lock().unwrap().len(), 4);
+
+    cache.resize(0);
+    cache.put("y".to_string(), 10);
+    assert!(cache.is_empty());
+    assert_eq!(evicted.lock().unwrap().last(), Some(&("y".to_string(), 10)));
+}
@@ -0,0 +1,69 @@
+use fast_list::MultiLinkedList;
+
+fn values(lists: &MultiLinkedList<u32, 3>, l: usize) -> Vec<u32> {
+    lists.iter(l).map(|(_, value)| *value).collect()
+}
+
+#[test]
+fn test_lists_are_independent() {
+    let mut lists = MultiLinkedList::<u32, 3>::new();
+    let nodes = (0..4).map(|value| lists.insert(value)).collect::<Vec<_>>();
+    assert_eq!(lists.head(0), None);
+
+    for &node in &nodes {
+        assert!(lists.push_back(0, node));
+        assert!(lists.push_front(1, node));
+    }
+    assert!(!lists.push_back(0, nodes[0]));
+    assert!(!lists.insert_after(2, nodes[1], nodes[0]));
+    assert!(lists.push_back(2, nodes[0]));
+    assert!(lists.insert_after(2, nodes[2], nodes[0]));
+    assert!(lists.insert_before(2, nodes[3], nodes[2]));
+
+    assert_eq!(values(&lists, 0), vec![0, 1, 2, 3]);
+    assert_eq!(values(&lists, 1), vec![3, 2, 1, 0]);
+    assert_eq!(values(&lists, 2), vec![0, 3, 2]);
+    assert_eq!(
+        lists
+            .iter_rev(2)
+            .map(|(_, value)| *value)
+            .collect::<Vec<_>>(),
+        vec![2, 3, 0]
+    );
+
+    assert!(lists.unlink(0, nodes[1]));
+    assert!(!lists.unlink(0, nodes[1]));
+    assert!(!lists.is_linked(0, nodes[1]));
+    assert!(lists.is_linked(1, nodes[1]));
+    assert_eq!(values(&lists, 0), vec![0, 2, 3]);
+    assert_eq!(values(&lists, 1), vec![3, 2, 1, 0]);
+
+    assert!(lists.move_to_back(1, nodes[3]));
+    assert_eq!(values(&lists, 1), vec![2, 1, 0, 3]);
+    assert_eq!(lists.next(1, nodes[0]), Some(nodes[3]));
+    assert_eq!(lists.prev(1, nodes[2]), None);
+}
+
+#[test]
+fn test_remove_unlinks_from_every_list() {
+    let mut lists = MultiLinkedList::<u32, 3>::new();
+    let nodes = (0..3).map(|value| lists.insert(value)).collect::<Vec<_>>();
+    for l in 0..3 {
+        for &node in &nodes {
+            lists.push_back(l, node);
+        }
+    }
+
+    assert_eq!(lists.remove(nodes[2]), Some(2));
+    assert_eq!(lists.remove(nodes[0]), Some(0));
+    assert_eq!(lists.remove(nodes[0]), None);
+    for l in 0..3 {
+        assert_eq!(values(&lists, l), vec![1]);
+        assert_eq!(lists.head(l), Some(nodes[1]));
+        assert_eq!(lists.tail(l), Some(nodes[1]));
+        assert_eq!(lists.len_of(l), 1);
+    }
+    *lists.get_mut(nodes[1]).unwrap() = 10;
+    assert_eq!(lists.get(nodes[1]), Some(&10));
+    assert_eq!(lists.len(), 1);
+}
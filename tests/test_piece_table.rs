@@ -0,0 +1,108 @@
+use fast_list::{PieceSource, PieceTable};
+
+struct XorShift(u32);
+
+impl XorShift {
+    fn next(&mut self, bound: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        self.0 as usize % bound
+    }
+}
+
+#[test]
+fn test_random_edits_match_string() {
+    let mut rng = XorShift(0x1234_5678);
+    let mut table = PieceTable::new("the quick brown fox\njumps over\nthe lazy dog");
+    let mut expected = table.to_string();
+    let words = ["a", "bc", "\n", "déf", "ghij "];
+
+    for _ in 0..500 {
+        if rng.next(3) > 0 || expected.is_empty() {
+            let mut offset = rng.next(expected.len() + 1);
+            while !expected.is_char_boundary(offset) {
+                offset -= 1;
+            }
+            let word = words[rng.next(words.len())];
+            table.insert(offset, word);
+            expected.insert_str(offset, word);
+        } else {
+            let mut start = rng.next(expected.len());
+            while !expected.is_char_boundary(start) {
+                start -= 1;
+            }
+            let mut end = (start + rng.next(8)).min(expected.len());
+            while !expected.is_char_boundary(end) {
+                end += 1;
+            }
+            table.delete(start..end);
+            expected.replace_range(start..end, "");
+        }
+        assert_eq!(table.len(), expected.len());
+    }
+
+    assert_eq!(table.to_string(), expected);
+    let lines = expected.split('\n').collect::<Vec<_>>();
+    assert_eq!(table.line_count(), lines.len());
+    for (n, line) in lines.iter().enumerate() {
+        assert_eq!(table.line_at(n).as_deref(), Some(*line));
+    }
+    assert_eq!(table.line_at(lines.len()), None);
+}
+
+#[test]
+fn test_piece_lookup_and_stable_handles() {
+    let mut table = PieceTable::new("abcdef");
+    let (original, at) = table.piece_at(2).unwrap();
+    assert_eq!(at, 2);
+
+    table.insert(3, "XY");
+    table.insert(5, "Z");
+    assert_eq!(table.to_string(), "abcXYZdef");
+    // Consecutive typing extends the same piece.
+    assert_eq!(table.pieces().len(), 3);
+
+    // The original handle keeps the first half of the split piece.
+    assert_eq!(table.piece_text(original), Some("abc"));
+    let (added, at) = table.piece_at(4).unwrap();
+    assert_eq!((table.piece_text(added), at), (Some("XYZ"), 1));
+    assert_eq!(
+        table.pieces().get(added).unwrap().value.source,
+        PieceSource::Add
+    );
+    assert_eq!(table.piece_at(9), None);
+
+    table.delete(0..2);
+    assert_eq!(table.piece_text(original), None);
+    assert_eq!(table.piece_text(added), Some("XYZ"));
+    assert_eq!(table.chunks().collect::<Vec<_>>(), vec!["c", "XYZ", "def"]);
+}
+
+#[test]
+fn test_marks_follow_edits() {
+    let mut table = PieceTable::new("0123456789");
+    let before = table.add_mark(2);
+    let inside = table.add_mark(5);
+    let at_end = table.add_mark(10);
+
+    table.insert(5, "ab");
+    assert_eq!(table.mark_offset(inside), Some(7));
+    table.insert(0, "x");
+    assert_eq!(table.mark_offset(before), Some(3));
+    assert_eq!(table.mark_offset(inside), Some(8));
+
+    table.delete(6..10);
+    assert_eq!(table.to_string(), "x01234789");
+    assert_eq!(table.mark_offset(inside), Some(6));
+
+    table.insert(table.len(), "!");
+    assert_eq!(table.mark_offset(at_end), Some(10));
+
+    table.delete(0..10);
+    assert_eq!(table.mark_offset(before), Some(0));
+    assert!(table.remove_mark(before));
+    assert!(!table.remove_mark(before));
+    assert_eq!(table.mark_offset(before), None);
+    assert!(table.is_empty());
+}
@@ -0,0 +1,120 @@
+use std::future::Future;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+use std::time::Duration;
+
+use fast_list::ListQueue;
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// A minimal executor that parks the thread until the future is woken up.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = Box::pin(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        thread::park();
+    }
+}
+
+#[test]
+fn test_multiple_producers_and_consumers() {
+    let queue = Arc::new(ListQueue::new());
+
+    let consumers = (0..4)
+        .map(|c| {
+            let queue = queue.clone();
+            thread::spawn(move || {
+                let mut received = Vec::new();
+                if c % 2 == 0 {
+                    while let Some(value) = queue.pop_blocking() {
+                        received.push(value);
+                    }
+                } else {
+                    while let Some(value) = block_on(queue.pop()) {
+                        received.push(value);
+                    }
+                }
+                received
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let producers = (0..4)
+        .map(|p| {
+            let queue = queue.clone();
+            thread::spawn(move || {
+                for i in 0..1_000 {
+                    queue.push(p * 1_000 + i).unwrap();
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for producer in producers {
+        producer.join().unwrap();
+    }
+    queue.close();
+
+    let mut received = consumers
+        .into_iter()
+        .flat_map(|consumer| consumer.join().unwrap())
+        .collect::<Vec<_>>();
+    received.sort();
+    assert_eq!(received, (0..4_000).collect::<Vec<_>>());
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn test_cancel_timeout_and_drain() {
+    let queue = ListQueue::new();
+    assert_eq!(queue.pop_timeout(Duration::from_millis(10)), None);
+
+    let indexes = (0..5).map(|i| queue.push(i).unwrap()).collect::<Vec<_>>();
+    assert_eq!(queue.cancel(indexes[1]), Some(1));
+    assert_eq!(queue.cancel(indexes[1]), None);
+    assert_eq!(queue.try_pop(), Some(0));
+    assert_eq!(queue.cancel(indexes[0]), None);
+    assert_eq!(queue.pop_timeout(Duration::from_secs(1)), Some(2));
+
+    assert_eq!(queue.drain(), vec![3, 4]);
+    assert_eq!(queue.cancel(indexes[3]), None);
+    assert_eq!(queue.len(), 0);
+    let new = queue.push(5).unwrap();
+    assert_eq!(queue.cancel(indexes[4]), None);
+    assert_eq!(queue.cancel(new), Some(5));
+}
+
+#[test]
+fn test_close_wakes_async_consumers() {
+    let queue = Arc::new(ListQueue::<i32>::new());
+    let waiting = (0..3)
+        .map(|_| {
+            let queue = queue.clone();
+            thread::spawn(move || block_on(queue.pop()))
+        })
+        .collect::<Vec<_>>();
+
+    queue.push(7).unwrap();
+    thread::sleep(Duration::from_millis(20));
+    queue.close();
+    assert_eq!(queue.push(8).unwrap_err().value, 8);
+    assert!(queue.is_closed());
+
+    let mut results = waiting
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect::<Vec<_>>();
+    results.sort();
+    assert_eq!(results, vec![None, None, Some(7)]);
+}
@@ -0,0 +1,109 @@
+use std::collections::BTreeMap;
+use std::ops::Bound;
+
+use fast_list::{SkipListMap, SkipListSet};
+
+#[test]
+fn test_matches_btree_map() {
+    let mut state = 0x1234_5678_9abc_def0_u64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    let mut map = SkipListMap::new();
+    let mut model = BTreeMap::new();
+    for i in 0..5_000u64 {
+        let key = next() % 1_000;
+        if next() % 3 == 0 {
+            assert_eq!(map.remove(&key), model.remove(&key));
+        } else {
+            assert_eq!(map.insert(key, i), model.insert(key, i));
+        }
+    }
+
+    assert_eq!(map.len(), model.len());
+    assert!(map.iter().eq(model.iter()));
+    for key in [0, 1, 250, 500, 999, 1_000] {
+        assert_eq!(map.get(&key), model.get(&key));
+        assert!(map.range(key..).eq(model.range(key..)));
+        assert!(map.range(..=key).eq(model.range(..=key)));
+        assert!(map
+            .range((Bound::Excluded(key), Bound::Excluded(key + 100)))
+            .eq(model.range((Bound::Excluded(key), Bound::Excluded(key + 100)))));
+        assert_eq!(
+            map.lower_bound(&key)
+                .and_then(|index| map.get_by_index(index)),
+            model.range(key..).next()
+        );
+    }
+    assert_eq!(map.first(), model.iter().next());
+    assert_eq!(map.last(), model.iter().next_back());
+}
+
+#[test]
+fn test_handles_and_bottom_list() {
+    let mut map = SkipListMap::new();
+    map.extend([(10, "a"), (30, "c"), (20, "b")]);
+
+    let handle = map.get_index(&20).unwrap();
+    map.insert(15, "x");
+    map.remove(&10);
+    assert_eq!(map.insert(20, "B"), Some("b"));
+    assert_eq!(map.get_index(&20), Some(handle));
+    *map.get_by_index_mut(handle).unwrap() = "bb";
+
+    // The bottom list can be walked with the usual cursors.
+    let list = map.list();
+    assert_eq!(list.next_of(handle).unwrap().value, (30, "c"));
+    assert_eq!(list.prev_of(handle).unwrap().value, (15, "x"));
+
+    assert_eq!(
+        map.upper_bound(&20).and_then(|i| map.get_by_index(i)),
+        Some((&30, &"c"))
+    );
+    assert_eq!(map.upper_bound(&30), None);
+    assert_eq!(map.pop_first(), Some((15, "x")));
+    assert_eq!(map.pop_last(), Some((30, "c")));
+    assert_eq!(map.remove_by_index(handle), Some((20, "bb")));
+    assert!(map.is_empty());
+    assert_eq!(map.remove_by_index(handle), None);
+
+    // Handles from before a clear don't match the entries added after it.
+    map.insert(1, "y");
+    let handle = map.get_index(&1).unwrap();
+    map.clear();
+    map.insert(2, "z");
+    assert_eq!(map.get_by_index(handle), None);
+}
+
+#[test]
+fn test_set() {
+    let mut set: SkipListSet<String> = ["pear", "apple", "fig"]
+        .into_iter()
+        .map(String::from)
+        .collect();
+    assert!(set.insert("kiwi".to_string()));
+    assert!(!set.insert("fig".to_string()));
+    assert!(set.contains("kiwi"));
+
+    assert_eq!(
+        set.iter().collect::<Vec<_>>(),
+        ["apple", "fig", "kiwi", "pear"]
+    );
+    assert_eq!(
+        set.range::<str, _>((Bound::Included("b"), Bound::Excluded("l")))
+            .collect::<Vec<_>>(),
+        ["fig", "kiwi"]
+    );
+    assert_eq!(set.first().map(String::as_str), Some("apple"));
+
+    assert!(set.remove("fig"));
+    assert_eq!(set.take("pear"), Some("pear".to_string()));
+    assert_eq!(set.len(), 2);
+    set.clear();
+    assert!(set.is_empty());
+    assert_eq!(set.pop_first(), None);
+}
@@ -0,0 +1,112 @@
+use fast_list::LinkedList;
+
+fn values<T: Clone>(list: &LinkedList<T>) -> Vec<T> {
+    list.head
+        .into_iter()
+        .flat_map(|head| list.iter_next(head))
+        .map(|item| item.value.clone())
+        .collect()
+}
+
+#[test]
+fn test_insert_sorted_with_hints() {
+    let mut list = LinkedList::new();
+    let mut state = 0x2545_f491u32;
+    let mut last = None;
+    for _ in 0..200 {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        last = Some(list.insert_sorted(state % 50, last));
+    }
+    assert_eq!(list.len(), 200);
+    assert!(list.is_sorted());
+
+    let mut expected = values(&list);
+    expected.sort();
+    assert_eq!(values(&list), expected);
+
+    // A stale hint falls back to scanning from both ends.
+    let stale = list.push_front(0);
+    list.remove(stale);
+    let index = list.insert_sorted(25, Some(stale));
+    assert!(list.is_sorted());
+    assert_eq!(list.get(index).unwrap().value, 25);
+}
+
+#[test]
+fn test_insert_sorted_without_hint_starts_at_the_closer_end() {
+    let mut list = LinkedList::new();
+    list.extend((0..1000).map(|i| i * 2));
+
+    // Values near either end only take a few comparisons, not a walk over the whole list.
+    for value in [3, 1995, -1, 2000] {
+        let mut comparisons = 0;
+        let index = list.insert_sorted_by(value, None, |a, b| {
+            comparisons += 1;
+            a.cmp(b)
+        });
+        assert_eq!(list.get(index).unwrap().value, value);
+        assert!(comparisons <= 8, "{value} took {comparisons} comparisons");
+    }
+    list.insert_sorted(1000, None);
+    assert!(list.is_sorted());
+    assert_eq!(list.len(), 1005);
+}
+
+#[test]
+fn test_insert_sorted_is_stable() {
+    let mut list = LinkedList::new();
+    for (key, tag) in [(2, 'a'), (1, 'b'), (2, 'c'), (0, 'd'), (2, 'e'), (1, 'f')] {
+        let hint = list.head;
+        list.insert_sorted_by_key((key, tag), hint, |(key, _)| *key);
+    }
+    assert_eq!(
+        values(&list),
+        vec![(0, 'd'), (1, 'b'), (1, 'f'), (2, 'a'), (2, 'c'), (2, 'e')]
+    );
+    assert!(list.is_sorted_by_key(|(key, _)| *key));
+    assert!(!list.is_sorted_by(|a, b| a.1 <= b.1));
+}
+
+#[test]
+fn test_merge_sorted() {
+    let mut a = LinkedList::new();
+    let kept = a.extend([(1, 'a'), (3, 'a'), (3, 'a'), (8, 'a')]);
+    let mut b = LinkedList::new();
+    let moved = b.extend([(0, 'b'), (3, 'b'), (9, 'b'), (10, 'b')]);
+
+    let remap = a.merge_sorted_by(b, |x, y| x.0.cmp(&y.0));
+    assert_eq!(
+        values(&a),
+        vec![
+            (0, 'b'),
+            (1, 'a'),
+            (3, 'a'),
+            (3, 'a'),
+            (3, 'b'),
+            (8, 'a'),
+            (9, 'b'),
+            (10, 'b')
+        ]
+    );
+    assert_eq!(remap.len(), 4);
+    assert_eq!(a.get(kept[0]).unwrap().value, (1, 'a'));
+    assert_eq!(a.cursor_next(kept[2]), Some(remap[moved[1]]));
+    for old in moved {
+        assert_eq!(a.get(remap[old]).unwrap().value.1, 'b');
+    }
+    assert_eq!(
+        a.head.map(|head| a.get(head).unwrap().value),
+        Some((0, 'b'))
+    );
+    assert_eq!(
+        a.tail.map(|tail| a.get(tail).unwrap().value),
+        Some((10, 'b'))
+    );
+
+    let mut empty = LinkedList::<i32>::new();
+    empty.merge_sorted(LinkedList::new());
+    assert!(empty.is_empty());
+    assert!(empty.is_sorted());
+}
@@ -0,0 +1,79 @@
+use fast_list::{MockClock, TimerWheel};
+
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+#[test]
+fn test_fires_in_deadline_order_across_levels() {
+    let mut rng = XorShift(0x9e37_79b9_7f4a_7c15);
+    let clock = MockClock::new(1_000);
+    let mut wheel = TimerWheel::starting_at(1_000);
+    let mut expected = Vec::new();
+
+    for id in 0..2_000u64 {
+        // Spread the deadlines over several levels of the wheel.
+        let delay = rng.next() % (1 << (rng.next() % 24));
+        let deadline = 1_000 + delay;
+        wheel.schedule(deadline, (deadline, id));
+        expected.push((deadline, id));
+    }
+    expected.sort_by_key(|(deadline, _)| *deadline);
+
+    let mut fired = Vec::new();
+    while !wheel.is_empty() {
+        clock.advance(rng.next() % 50_000);
+        let now = fast_list::Clock::now(&clock);
+        for (deadline, id) in wheel.advance(now) {
+            assert!(deadline <= now);
+            fired.push((deadline, id));
+        }
+        assert_eq!(wheel.len() + fired.len(), expected.len());
+    }
+
+    let deadlines = |timers: &[(u64, u64)]| timers.iter().map(|(d, _)| *d).collect::<Vec<_>>();
+    assert_eq!(deadlines(&fired), deadlines(&expected));
+    let mut ids = fired.iter().map(|(_, id)| *id).collect::<Vec<_>>();
+    ids.sort();
+    assert_eq!(ids, (0..2_000).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_cancel_and_due_timers() {
+    let clock = MockClock::new(0);
+    let mut wheel = TimerWheel::new();
+    let handles = (0..10)
+        .map(|i| wheel.schedule(i * 100, i))
+        .collect::<Vec<_>>();
+
+    assert_eq!(wheel.cancel(handles[3]), Some(3));
+    assert_eq!(wheel.cancel(handles[3]), None);
+    *wheel.get_mut(handles[5]).unwrap() = 50;
+
+    clock.set(550);
+    assert_eq!(wheel.poll(&clock).collect::<Vec<_>>(), vec![0, 1, 2, 4, 50]);
+    assert!(!wheel.contains_key(handles[5]));
+    assert_eq!(wheel.deadline(handles[9]), Some(900));
+
+    // Timers in the past fire on the next advance, even without time passing.
+    let late = wheel.schedule(10, 100);
+    assert_eq!(wheel.get(late), Some(&100));
+    assert_eq!(wheel.poll(&clock).collect::<Vec<_>>(), vec![100]);
+
+    let soon = wheel.schedule_after(1, 200);
+    assert_eq!(wheel.deadline(soon), Some(551));
+    assert_eq!(wheel.cancel(soon), Some(200));
+
+    assert_eq!(
+        wheel.advance(u64::MAX).collect::<Vec<_>>(),
+        vec![6, 7, 8, 9]
+    );
+    assert!(wheel.is_empty());
+}
@@ -0,0 +1,103 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use fast_list::{Change, LinkedList};
+
+fn values(list: &LinkedList<i32>) -> Vec<i32> {
+    if list.is_empty() {
+        return Vec::new();
+    }
+    list.iter().map(|item| item.value).collect()
+}
+
+#[test]
+fn test_rollback_restores_indexes() {
+    let mut list = LinkedList::new();
+    let indexes = list.extend(0..5);
+
+    let result: Result<((), _), &str> = list.transaction(|tx| {
+        tx.remove(indexes[0]);
+        tx.remove(indexes[4]);
+        tx.move_after(indexes[1], indexes[3]);
+        tx.replace(indexes[2], 20);
+        let new = tx.push_front(10);
+        tx.insert_after(new, 11);
+        assert_eq!(tx.len(), 5);
+        assert_eq!(
+            tx.iter().map(|item| item.value).collect::<Vec<_>>(),
+            vec![10, 11, 20, 3, 1]
+        );
+        Err("abort")
+    });
+
+    assert_eq!(result.unwrap_err(), "abort");
+    assert_eq!(values(&list), vec![0, 1, 2, 3, 4]);
+    assert_eq!(list.head, Some(indexes[0]));
+    assert_eq!(list.tail, Some(indexes[4]));
+    assert_eq!(list.len(), 5);
+    for (value, index) in indexes.iter().enumerate() {
+        assert_eq!(list.get(*index).unwrap().value, value as i32);
+    }
+}
+
+#[test]
+fn test_commit_reports_changes() {
+    let mut list = LinkedList::new();
+    let indexes = list.extend(0..3);
+
+    let (popped, changes) = list
+        .transaction(|tx| {
+            let popped = tx.pop_front();
+            assert!(tx.get(indexes[0]).is_none());
+            tx.update(indexes[1], |value| *value *= 10);
+            Ok::<_, ()>(popped)
+        })
+        .unwrap();
+
+    assert_eq!(popped, Some(0));
+    assert_eq!(values(&list), vec![10, 2]);
+    assert_eq!(list.len(), 2);
+    assert!(!list.contains_key(indexes[0]));
+    assert_eq!(
+        changes.changes,
+        vec![
+            Change::Remove { index: indexes[0] },
+            Change::Update {
+                index: indexes[1],
+                value: 10
+            },
+        ]
+    );
+
+    let (_, changes) = list.transaction(|tx| Ok::<_, ()>(tx.push_back(3))).unwrap();
+    let index = list.tail.unwrap();
+    assert_eq!(
+        changes.into_iter().collect::<Vec<_>>(),
+        vec![Change::Insert {
+            index,
+            prev: Some(indexes[2]),
+            next: None,
+            value: 3
+        }]
+    );
+}
+
+#[test]
+fn test_panic_rolls_back() {
+    let mut list = LinkedList::new();
+    let indexes = list.extend(0..3);
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let _ = list.transaction(|tx| {
+            tx.remove(indexes[1]);
+            tx.push_back(3);
+            if tx.len() == 3 {
+                panic!("closure panicked");
+            }
+            Ok::<_, ()>(())
+        });
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(values(&list), vec![0, 1, 2]);
+    assert_eq!(list.len(), 3);
+}
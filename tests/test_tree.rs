@@ -0,0 +1,57 @@
+use fast_list::{ArenaTree, TreeIndex};
+
+fn values(tree: &ArenaTree<u32>, order: impl Iterator<Item = TreeIndex>) -> Vec<u32> {
+    order.map(|index| tree.get(index).unwrap().value).collect()
+}
+
+#[test]
+fn test_build_and_traverse() {
+    let mut tree = ArenaTree::new();
+    let root = tree.new_node(1);
+    let b = tree.append_child(root, 3);
+    let a = tree.prepend_child(root, 2);
+    tree.insert_sibling_before(b, 4);
+    let leaf = tree.append_child(a, 5);
+    tree.insert_sibling_after(leaf, 6);
+
+    assert_eq!(values(&tree, tree.children(root)), vec![2, 4, 3]);
+    assert_eq!(values(&tree, tree.pre_order(root)), vec![1, 2, 5, 6, 4, 3]);
+    assert_eq!(values(&tree, tree.post_order(root)), vec![5, 6, 2, 4, 3, 1]);
+    assert_eq!(values(&tree, tree.bfs(root)), vec![1, 2, 4, 3, 5, 6]);
+    assert_eq!(values(&tree, tree.ancestors(leaf)), vec![5, 2, 1]);
+    assert_eq!(values(&tree, tree.pre_order(leaf)), vec![5]);
+    assert_eq!(values(&tree, tree.post_order(leaf)), vec![5]);
+
+    let node = tree.get(a).unwrap();
+    assert_eq!(node.parent(), Some(root));
+    assert_eq!(node.first_child(), Some(leaf));
+    assert_eq!(node.prev_sibling(), None);
+}
+
+#[test]
+fn test_detach_reparent_and_remove() {
+    let mut tree = ArenaTree::new();
+    let root = tree.new_node(1);
+    let a = tree.append_child(root, 2);
+    let b = tree.append_child(root, 3);
+    let a1 = tree.append_child(a, 4);
+
+    // A node can't become a child of itself or of one of its descendants.
+    assert!(!tree.reparent(a, a1));
+    assert!(!tree.reparent(a, a));
+    assert!(tree.reparent(a, b));
+    assert_eq!(values(&tree, tree.pre_order(root)), vec![1, 3, 2, 4]);
+
+    assert!(tree.detach(a));
+    assert_eq!(tree.get(a).unwrap().parent(), None);
+    assert_eq!(values(&tree, tree.pre_order(root)), vec![1, 3]);
+    assert_eq!(values(&tree, tree.pre_order(a)), vec![2, 4]);
+    assert_eq!(tree.get(b).unwrap().first_child(), None);
+
+    assert_eq!(tree.remove(a), Some(2));
+    assert!(!tree.contains_key(a1));
+    assert_eq!(tree.len(), 2);
+    assert_eq!(tree.remove(a), None);
+    assert!(!tree.detach(a));
+    assert_eq!(tree.pre_order(a).count(), 0);
+}
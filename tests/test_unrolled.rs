@@ -0,0 +1,93 @@
+use fast_list::{UnrolledIndex, UnrolledList};
+
+fn values<const N: usize>(list: &UnrolledList<u32, N>) -> Vec<u32> {
+    list.iter().map(|(_, value)| *value).collect()
+}
+
+#[test]
+fn test_push_pop_and_split() {
+    let mut list = UnrolledList::<u32, 4>::new();
+    assert_eq!(list.pop_front(), None);
+    assert_eq!(list.front(), None);
+
+    let handles = list.extend(0..8);
+    list.push_front(100);
+    assert_eq!(list.chunk_count(), 3);
+    assert_eq!(values(&list), vec![100, 0, 1, 2, 3, 4, 5, 6, 7]);
+
+    // Inserting into a full chunk splits it, the handles keep pointing at the same values.
+    list.insert_before(handles[5], 50);
+    assert_eq!(values(&list), vec![100, 0, 1, 2, 3, 4, 50, 5, 6, 7]);
+    for (value, handle) in handles.iter().enumerate() {
+        assert_eq!(list.get(*handle), Some(&(value as u32)));
+    }
+    assert_eq!(list.nth(6).and_then(|handle| list.get(handle)), Some(&50));
+
+    assert_eq!(list.pop_front(), Some(100));
+    assert_eq!(list.pop_back(), Some(7));
+    assert_eq!(list.back(), Some(&6));
+    *list.get_mut(handles[0]).unwrap() = 10;
+    assert_eq!(list.front(), Some(&10));
+}
+
+#[test]
+fn test_remove_merges_chunks() {
+    let mut list = UnrolledList::<u32, 4>::new();
+    let handles = list.extend(0..12);
+    assert_eq!(list.chunk_count(), 3);
+
+    list.remove(handles[0]);
+    list.remove(handles[1]);
+    list.remove(handles[4]);
+    list.remove(handles[5]);
+    assert_eq!(list.chunk_count(), 3);
+    // The first chunk drops below half full and the next chunk fits, so they are merged.
+    list.remove(handles[2]);
+    assert_eq!(list.chunk_count(), 2);
+    assert_eq!(values(&list), vec![3, 6, 7, 8, 9, 10, 11]);
+    assert_eq!(list.get(handles[7]), Some(&7));
+    assert_eq!(list.remove(handles[0]), None);
+}
+
+#[test]
+fn test_matches_vec_model() {
+    let mut state = 0x2545_f491_u32;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state
+    };
+
+    let mut list = UnrolledList::<u32, 8>::new();
+    let mut model: Vec<(UnrolledIndex, u32)> = Vec::new();
+    for value in 0..2_000 {
+        let roll = next();
+        if model.is_empty() || roll % 3 != 0 {
+            let handle = match model.len() {
+                0 => list.push_back(value),
+                len => {
+                    let at = next() as usize % len;
+                    let handle = list.insert_after(model[at].0, value);
+                    model.insert(at + 1, (handle, value));
+                    continue;
+                }
+            };
+            model.push((handle, value));
+        } else {
+            let at = next() as usize % model.len();
+            let (handle, value) = model.remove(at);
+            assert_eq!(list.remove(handle), Some(value));
+        }
+    }
+
+    assert_eq!(list.len(), model.len());
+    assert_eq!(
+        list.iter().collect::<Vec<_>>(),
+        model
+            .iter()
+            .map(|(handle, value)| (*handle, value))
+            .collect::<Vec<_>>()
+    );
+    assert!(list.chunk_count() <= model.len());
+}